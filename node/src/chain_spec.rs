@@ -1,12 +1,17 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	pallet_bhdao::Roles, AccountId, AuraConfig, Balance, BalancesConfig, BhdaoModuleConfig,
+	GenesisConfig, GrandpaConfig, Signature, SS58Prefix, SudoConfig, SystemConfig, WASM_BINARY,
 };
-use sc_service::ChainType;
+use sc_service::{config::MultiaddrWithPeerId, ChainType, Properties};
+use serde::{Deserialize, Deserializer};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
+use sp_core::{
+	crypto::{set_default_ss58_version, Ss58AddressFormat, Ss58Codec},
+	ed25519, sr25519, Pair, Public,
+};
 use sp_runtime::traits::{IdentifyAccount, Verify};
+use std::path::Path;
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -74,7 +79,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
 		None,
 		None,
 		// Properties
-		None,
+		Some(chain_properties("UNIT", 12, SS58Prefix::get())),
 		// Extensions
 		None,
 	))
@@ -120,9 +125,9 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 		None,
 		// Protocol ID
 		None,
-		// Properties
-		None,
 		None,
+		// Properties
+		Some(chain_properties("UNIT", 12, SS58Prefix::get())),
 		// Extensions
 		None,
 	))
@@ -156,5 +161,208 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		bhdao_module: Default::default(),
+	}
+}
+
+/// Build the `properties` section of a chain spec.
+pub fn chain_properties(token_symbol: &str, token_decimals: u8, ss58_format: u16) -> Properties {
+	let mut properties = Properties::new();
+	properties.insert("tokenSymbol".into(), token_symbol.into());
+	properties.insert("tokenDecimals".into(), token_decimals.into());
+	properties.insert("ss58Format".into(), ss58_format.into());
+	properties
+}
+
+/// Description of a staging or production network, read from a TOML or JSON file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChainSpecInput {
+	pub name: String,
+	pub id: String,
+	#[serde(default = "default_chain_type")]
+	pub chain_type: String,
+	pub protocol_id: Option<String>,
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	pub properties: TokenProperties,
+	pub authorities: Vec<AuthorityInput>,
+	pub sudo: Option<String>,
+	#[serde(default)]
+	pub endowed: Vec<EndowedInput>,
+	#[serde(default)]
+	pub members: Vec<MemberInput>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenProperties {
+	pub token_symbol: String,
+	pub token_decimals: u8,
+}
+
+/// SS58 encoded Aura (sr25519) and Grandpa (ed25519) keys of one authority.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorityInput {
+	pub aura: String,
+	pub grandpa: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EndowedInput {
+	pub account: String,
+	/// A decimal string, as TOML integers cannot hold a full `u128`.
+	#[serde(deserialize_with = "deserialize_balance")]
+	pub balance: Balance,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MemberInput {
+	pub account: String,
+	pub role: Roles,
+	#[serde(default)]
+	pub metadata: String,
+}
+
+fn default_chain_type() -> String {
+	"Live".into()
+}
+
+fn deserialize_balance<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+	let balance = String::deserialize(deserializer)?;
+	balance.replace('_', "").parse().map_err(|e| {
+		serde::de::Error::custom(format!("Invalid balance {}: {}", balance, e))
+	})
+}
+
+fn parse_account(account: &str) -> Result<AccountId, String> {
+	AccountId::from_ss58check(account).map_err(|e| format!("Invalid account {}: {:?}", account, e))
+}
+
+impl ChainSpecInput {
+	/// Read the input from `path`. Files ending in `.toml` are parsed as TOML, anything else as
+	/// JSON.
+	pub fn from_file(path: &Path) -> Result<Self, String> {
+		let content = std::fs::read_to_string(path)
+			.map_err(|e| format!("Error reading {}: {}", path.display(), e))?;
+
+		match path.extension().and_then(|e| e.to_str()) {
+			Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+			_ => serde_json::from_str(&content).map_err(|e| e.to_string()),
+		}
+	}
+}
+
+/// Build a chain spec from a parsed [`ChainSpecInput`].
+pub fn configured_config(input: ChainSpecInput) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Wasm binary not available".to_string())?;
+
+	let chain_type = match input.chain_type.as_str() {
+		"Development" => ChainType::Development,
+		"Local" => ChainType::Local,
+		"Live" => ChainType::Live,
+		other => return Err(format!("Unknown chain type {}", other)),
+	};
+
+	// Accept keys in the network's own address format as well as the generic Substrate one.
+	set_default_ss58_version(Ss58AddressFormat::custom(SS58Prefix::get()));
+
+	if input.authorities.is_empty() {
+		return Err("At least one authority is required".into())
+	}
+
+	let initial_authorities = input
+		.authorities
+		.iter()
+		.map(|a| {
+			let aura = sr25519::Public::from_ss58check(&a.aura)
+				.map_err(|e| format!("Invalid aura key {}: {:?}", a.aura, e))?;
+			let grandpa = ed25519::Public::from_ss58check(&a.grandpa)
+				.map_err(|e| format!("Invalid grandpa key {}: {:?}", a.grandpa, e))?;
+			Ok((aura.into(), grandpa.into()))
+		})
+		.collect::<Result<Vec<(AuraId, GrandpaId)>, String>>()?;
+
+	let root_key = input.sudo.as_deref().map(parse_account).transpose()?;
+
+	let endowed_accounts = input
+		.endowed
+		.iter()
+		.map(|e| Ok((parse_account(&e.account)?, e.balance)))
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let members = input
+		.members
+		.iter()
+		.map(|m| {
+			if m.role == Roles::None {
+				return Err(format!("Member {} has no role", m.account))
+			}
+			Ok((parse_account(&m.account)?, m.role, m.metadata.as_bytes().to_vec()))
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let boot_nodes = input
+		.boot_nodes
+		.iter()
+		.map(|b| {
+			b.parse::<MultiaddrWithPeerId>()
+				.map_err(|e| format!("Invalid boot node {}: {}", b, e))
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let properties = chain_properties(
+		&input.properties.token_symbol,
+		input.properties.token_decimals,
+		SS58Prefix::get(),
+	);
+
+	Ok(ChainSpec::from_genesis(
+		&input.name,
+		&input.id,
+		chain_type,
+		move || {
+			configured_genesis(
+				wasm_binary,
+				initial_authorities.clone(),
+				root_key.clone(),
+				endowed_accounts.clone(),
+				members.clone(),
+			)
+		},
+		boot_nodes,
+		// Telemetry
+		None,
+		input.protocol_id.as_deref(),
+		None,
+		Some(properties),
+		// Extensions
+		None,
+	))
+}
+
+/// Genesis state for a configured network: explicit balances and the founding DAO members.
+fn configured_genesis(
+	wasm_binary: &[u8],
+	initial_authorities: Vec<(AuraId, GrandpaId)>,
+	root_key: Option<AccountId>,
+	endowed_accounts: Vec<(AccountId, Balance)>,
+	members: Vec<(AccountId, Roles, Vec<u8>)>,
+) -> GenesisConfig {
+	GenesisConfig {
+		system: SystemConfig { code: wasm_binary.to_vec() },
+		balances: BalancesConfig { balances: endowed_accounts },
+		aura: AuraConfig {
+			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
+		},
+		grandpa: GrandpaConfig {
+			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
+		},
+		sudo: SudoConfig { key: root_key },
+		transaction_payment: Default::default(),
+		bhdao_module: BhdaoModuleConfig { members },
 	}
 }
@@ -1,4 +1,5 @@
 use sc_cli::RunCmd;
+use std::path::PathBuf;
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -18,6 +19,9 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Generate plain and raw chain specifications from a TOML or JSON network description.
+	GenerateSpec(GenerateSpecCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
@@ -51,3 +55,15 @@ pub enum Subcommand {
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
 }
+
+#[derive(Debug, clap::Parser)]
+pub struct GenerateSpecCmd {
+	/// Network description (authorities, sudo, endowed accounts, DAO members, properties and
+	/// boot nodes). Parsed as TOML if the extension is `.toml`, otherwise as JSON.
+	#[arg(long)]
+	pub input: PathBuf,
+
+	/// Directory to write `<id>.json` and `<id>-raw.json` into.
+	#[arg(long, default_value = ".")]
+	pub output_dir: PathBuf,
+}
@@ -62,6 +62,21 @@ pub fn run() -> sc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
 		},
+		Some(Subcommand::GenerateSpec(cmd)) => {
+			let input = chain_spec::ChainSpecInput::from_file(&cmd.input)?;
+			let spec = chain_spec::configured_config(input)?;
+
+			std::fs::create_dir_all(&cmd.output_dir)?;
+			for (raw, file_name) in
+				[(false, format!("{}.json", spec.id())), (true, format!("{}-raw.json", spec.id()))]
+			{
+				let path = cmd.output_dir.join(file_name);
+				std::fs::write(&path, spec.as_json(raw)?)?;
+				println!("Wrote {}", path.display());
+			}
+
+			Ok(())
+		},
 		Some(Subcommand::CheckBlock(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
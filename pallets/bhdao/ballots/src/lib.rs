@@ -0,0 +1,227 @@
+//! Ballot engine shared by every BHDAO vote type.
+//!
+//! A ballot covers the blocks `start..end`. Voting opens in block `start` and the last block
+//! to vote in is `end - 1`. From block `end` on the ballot is closed and can be finalized, so
+//! no block is in both states or in neither. The reveal window of a commit-reveal ballot follows
+//! the same rule over `end..reveal_end`.
+//!
+//! What happens once a ballot is decided is left to a [`BallotHandler`], so a new kind of
+//! ballot only needs a new arm in the handler, not another copy of the tally code.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchResult, Perbill};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BallotStatus {
+	InProgress,
+	Passed,
+	Failed,
+	/// Failed because enough of the weight cast was `NoWithVeto`.
+	Vetoed,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VoteChoice {
+	Aye,
+	Nay,
+	/// Counts toward turnout but not toward the outcome.
+	Abstain,
+	/// A nay that also counts toward vetoing the ballot.
+	NoWithVeto,
+}
+
+impl From<bool> for VoteChoice {
+	fn from(aye: bool) -> Self {
+		if aye { VoteChoice::Aye } else { VoteChoice::Nay }
+	}
+}
+
+impl VoteChoice {
+	/// Whether the choice sided with the outcome of a ballot. Abstentions side with neither.
+	pub fn agrees_with(&self, passed: bool) -> bool {
+		match self {
+			VoteChoice::Aye => passed,
+			VoteChoice::Nay | VoteChoice::NoWithVeto => !passed,
+			VoteChoice::Abstain => false,
+		}
+	}
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Tally {
+	/// Head counts, kept for transparency whatever the weighting.
+	pub yes_votes: u64,
+	pub no_votes: u64,
+	pub abstain_votes: u64,
+	/// `NoWithVeto` votes, also counted in `no_votes`.
+	pub veto_votes: u64,
+	/// Weighted tallies the outcome is decided on.
+	pub yes_weight: u128,
+	pub no_weight: u128,
+	pub abstain_weight: u128,
+	pub veto_weight: u128,
+}
+
+impl Tally {
+	/// Count one vote carrying `weight`.
+	pub fn add(&mut self, choice: VoteChoice, weight: u128) {
+		match choice {
+			VoteChoice::Aye => self.yes_votes = self.yes_votes.saturating_add(1),
+			VoteChoice::Nay => self.no_votes = self.no_votes.saturating_add(1),
+			VoteChoice::Abstain => self.abstain_votes = self.abstain_votes.saturating_add(1),
+			VoteChoice::NoWithVeto => {
+				self.no_votes = self.no_votes.saturating_add(1);
+				self.veto_votes = self.veto_votes.saturating_add(1);
+			},
+		}
+		self.add_weight(choice, weight);
+	}
+
+	/// Move `weight` onto `choice` without counting another voter, as delegations do.
+	pub fn add_weight(&mut self, choice: VoteChoice, weight: u128) {
+		match choice {
+			VoteChoice::Aye => self.yes_weight = self.yes_weight.saturating_add(weight),
+			VoteChoice::Nay => self.no_weight = self.no_weight.saturating_add(weight),
+			VoteChoice::Abstain => self.abstain_weight = self.abstain_weight.saturating_add(weight),
+			VoteChoice::NoWithVeto => {
+				self.no_weight = self.no_weight.saturating_add(weight);
+				self.veto_weight = self.veto_weight.saturating_add(weight);
+			},
+		}
+	}
+
+	/// Take `weight` back off `choice`.
+	pub fn remove_weight(&mut self, choice: VoteChoice, weight: u128) {
+		match choice {
+			VoteChoice::Aye => self.yes_weight = self.yes_weight.saturating_sub(weight),
+			VoteChoice::Nay => self.no_weight = self.no_weight.saturating_sub(weight),
+			VoteChoice::Abstain => self.abstain_weight = self.abstain_weight.saturating_sub(weight),
+			VoteChoice::NoWithVeto => {
+				self.no_weight = self.no_weight.saturating_sub(weight);
+				self.veto_weight = self.veto_weight.saturating_sub(weight);
+			},
+		}
+	}
+
+	/// All weight cast, abstentions included.
+	pub fn turnout(&self) -> u128 {
+		self.yes_weight.saturating_add(self.no_weight).saturating_add(self.abstain_weight)
+	}
+
+	/// A ballot passes on a strict weighted majority, so ties fail. Abstentions don't count.
+	pub fn passed(&self) -> bool {
+		self.yes_weight > self.no_weight
+	}
+
+	/// Whether vetoes make up at least `threshold` of the ayes and nays.
+	pub fn vetoed(&self, threshold: Perbill) -> bool {
+		self.veto_weight > 0 && self.veto_weight >= threshold.mul_ceil(self.yes_weight.saturating_add(self.no_weight))
+	}
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct Ballot<BlockNumber> {
+	pub tally: Tally,
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+	pub status: BallotStatus,
+}
+
+/// Why a ballot can't be voted on or finalized yet.
+#[derive(Clone, PartialEq, Debug, Eq, Copy)]
+pub enum BallotError {
+	/// The ballot has already been finalized.
+	NotInProgress,
+	/// Voting hasn't started or is over.
+	NotOpen,
+	/// Voting isn't over yet.
+	StillOpen,
+}
+
+/// Reacts to ballots of kind `Kind` being finalized.
+pub trait BallotHandler<Kind, Id> {
+	/// Carry out what follows from ballot `id` passing or failing, such as opening the ballot
+	/// for the next stage.
+	fn on_finalized(kind: Kind, id: Id, passed: bool) -> DispatchResult;
+}
+
+impl<Kind, Id> BallotHandler<Kind, Id> for () {
+	fn on_finalized(_kind: Kind, _id: Id, _passed: bool) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Whether `now` falls in the window `start..end`.
+pub fn is_open<B: PartialOrd>(start: B, end: B, now: B) -> bool {
+	start <= now && now < end
+}
+
+/// Whether the window ending at `end` is over at `now`.
+pub fn is_closed<B: PartialOrd>(end: B, now: B) -> bool {
+	now >= end
+}
+
+impl<B: PartialOrd + Copy> Ballot<B> {
+	/// An empty ballot open for voting over `start..end`.
+	pub fn new(start: B, end: B) -> Self {
+		Ballot {
+			tally: Tally::default(),
+			start,
+			end,
+			status: BallotStatus::InProgress,
+		}
+	}
+
+	pub fn is_open(&self, now: B) -> bool {
+		is_open(self.start, self.end, now)
+	}
+
+	pub fn is_closed(&self, now: B) -> bool {
+		is_closed(self.end, now)
+	}
+
+	/// Ensure votes can be cast at `now`.
+	pub fn ensure_open(&self, now: B) -> Result<(), BallotError> {
+		if self.status != BallotStatus::InProgress {
+			return Err(BallotError::NotInProgress)
+		}
+		if !self.is_open(now) {
+			return Err(BallotError::NotOpen)
+		}
+		Ok(())
+	}
+
+	/// Ensure the ballot can be finalized at `now`.
+	pub fn ensure_closed(&self, now: B) -> Result<(), BallotError> {
+		if self.status != BallotStatus::InProgress {
+			return Err(BallotError::NotInProgress)
+		}
+		if !self.is_closed(now) {
+			return Err(BallotError::StillOpen)
+		}
+		Ok(())
+	}
+
+	pub fn passed(&self) -> bool {
+		self.tally.passed()
+	}
+
+	/// Record the outcome of the ballot.
+	pub fn conclude(&mut self, passed: bool) {
+		self.status = if passed { BallotStatus::Passed } else { BallotStatus::Failed };
+	}
+
+	/// Record that the ballot failed on vetoes.
+	pub fn veto(&mut self) {
+		self.status = BallotStatus::Vetoed;
+	}
+}
@@ -0,0 +1,34 @@
+//! Runtime API for querying the BHDAO pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BhdaoApi<AccountId, Role, Requirement, BallotKind, Tally, TokenId, License>
+	where
+		AccountId: Codec,
+		Role: Codec,
+		Requirement: Codec,
+		BallotKind: Codec,
+		Tally: Codec,
+		TokenId: Codec,
+		License: Codec,
+	{
+		/// Experts holding an expertise domain.
+		fn experts_in_domain(domain: u32) -> Vec<AccountId>;
+		/// Expertise domains held by an Expert.
+		fn domains_of(who: AccountId) -> Vec<u32>;
+		/// Requirements a member still has to meet for each role they could move into.
+		fn role_progress(who: AccountId) -> Vec<(Role, Vec<Requirement>)>;
+		/// Ayes, nays, abstentions and vetoes cast on a ballot so far.
+		fn ballot_tally(vote_type: BallotKind, uid: u64) -> Option<Tally>;
+		/// Tokens minted for the uploads of a collection.
+		fn collection_tokens(collection: u64) -> Vec<TokenId>;
+		/// License chosen for an upload.
+		fn upload_license(upload_id: u64) -> Option<License>;
+		/// License a minted token is published under.
+		fn token_license(token: TokenId) -> Option<License>;
+	}
+}
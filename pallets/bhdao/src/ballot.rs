@@ -0,0 +1,202 @@
+//! The pallet's side of the ballot engine in `pallet-bhdao-ballots`: the windows of its own
+//! ballot-like records and what each vote type moves on to once its ballot is finalized.
+
+use crate::{
+	Appeals, Config, Error, Event, ExpertDomains, ExpertReview, ExpertReviews, MemberProposals, Members, ObjectionStatus,
+	Pallet, PendingAttestations, RejectionPenalties, Rejections, Roles, SecretBallot, UploadStatus, Vote, VoteStatus,
+	VoteType, Votes,
+};
+use frame_support::traits::{Get, ReservableCurrency};
+use pallet_bhdao_ballots::{is_closed, is_open, BallotError, BallotHandler};
+use sp_runtime::{DispatchError, DispatchResult};
+
+impl<T: Config> From<BallotError> for Error<T> {
+	fn from(error: BallotError) -> Self {
+		match error {
+			BallotError::NotInProgress => Error::<T>::VoteNotInProgress,
+			BallotError::NotOpen => Error::<T>::VotingWindowNotValid,
+			BallotError::StillOpen => Error::<T>::VoteStillInProgress,
+		}
+	}
+}
+
+impl<T: Config> SecretBallot<T> {
+	/// Whether votes on the ballot `vote` can be revealed at `now`.
+	pub fn is_revealing(&self, vote: &Vote<T>, now: T::BlockNumber) -> bool {
+		is_open(vote.end, self.reveal_end, now)
+	}
+
+	pub fn is_closed(&self, now: T::BlockNumber) -> bool {
+		is_closed(self.reveal_end, now)
+	}
+}
+
+impl<T: Config> ExpertReview<T> {
+	pub fn is_open(&self, now: T::BlockNumber) -> bool {
+		is_open(self.start, self.end, now)
+	}
+
+	pub fn is_closed(&self, now: T::BlockNumber) -> bool {
+		is_closed(self.end, now)
+	}
+}
+
+impl<T: Config> BallotHandler<VoteType, u64> for Pallet<T> {
+	fn on_finalized(vote_type: VoteType, uid: u64, passed: bool) -> DispatchResult {
+		if vote_type == VoteType::Qualification {
+			PendingAttestations::<T>::remove(uid);
+		}
+		match (vote_type, passed) {
+			(VoteType::Qualification, true) => Self::start_verification_vote(uid),
+			(VoteType::Verification, true) => Self::start_expert_review(uid),
+			(VoteType::Qualification, false) | (VoteType::Verification, false) => Self::reject_upload(uid),
+			(VoteType::Proposal, passed) => {
+				Self::release_member_action_deposit(uid, passed);
+				if passed {
+					return Self::enact_member_action(uid)
+				}
+				MemberProposals::<T>::remove(uid);
+				Ok(())
+			},
+			(VoteType::Objection, upheld) => Self::resolve_objection(uid, upheld),
+			(VoteType::Appeal, passed) => Self::resolve_appeal(uid, passed),
+			(VoteType::Amendment, passed) => {
+				Self::release_amendment_deposit(uid, passed);
+				if passed {
+					return Self::apply_amendment(uid)
+				}
+				Self::reject_amendment(uid)
+			},
+			(VoteType::CollectionReview, passed) => Self::review_collection(uid, passed),
+			(VoteType::Takedown, passed) => {
+				Self::release_takedown_deposit(uid, Self::is_vetoed(vote_type, uid));
+				if passed {
+					return Self::redact_upload(uid)
+				}
+				Self::reject_takedown(uid)
+			},
+			(VoteType::ExpertVerification, true) => Self::open_council_ballot(VoteType::ExpertCouncilApproval, uid),
+			(VoteType::CuratorVerification, true) => Self::open_council_ballot(VoteType::CuratorCouncilApproval, uid),
+			(VoteType::CollectorVerification, true) => Self::open_council_ballot(VoteType::CollectorCouncilApproval, uid),
+			(VoteType::ExpertCouncilApproval, true) => {
+				let applicant = Self::grant_role(uid, Roles::Expert)?;
+				if let (Some(applicant), Some(domain)) = (applicant, Self::get_application_domain(uid)) {
+					ExpertDomains::<T>::insert(domain, &applicant, true);
+					Self::deposit_event(Event::ExpertiseGranted { who: applicant, domain });
+				}
+				Ok(())
+			},
+			(VoteType::CuratorCouncilApproval, true) => {
+				Self::grant_role(uid, Roles::Verifier)?;
+				Ok(())
+			},
+			(VoteType::CollectorCouncilApproval, true) => {
+				if let Some(applicant) = Self::grant_role(uid, Roles::Collector)? {
+					Self::deposit_event(Event::CollectorApproved { who: applicant });
+				}
+				Ok(())
+			},
+			(VoteType::ExpertVerification, false) | (VoteType::CuratorVerification, false) |
+			(VoteType::CollectorVerification, false) | (VoteType::ExpertCouncilApproval, false) |
+			(VoteType::CuratorCouncilApproval, false) | (VoteType::CollectorCouncilApproval, false) => {
+				Self::release_application_deposit(uid, Self::is_vetoed(vote_type, uid));
+				Ok(())
+			},
+			_ => Ok(()),
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return the deposit of an upheld objection and penalize the contributor, or slash the
+	/// deposit of a dismissed one. The review itself is concluded by `finalize_expert_review`.
+	fn resolve_objection(objection_id: u64, upheld: bool) -> DispatchResult {
+		let upload_id = Self::get_objection_upload(objection_id).ok_or(Error::<T>::ObjectionNotFound)?;
+		let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+		ExpertReviews::<T>::try_mutate(upload_id, |review| -> DispatchResult {
+			let objection = review
+				.as_mut()
+				.and_then(|review| review.objections.as_mut())
+				.and_then(|objections| objections.iter_mut().find(|objection| objection.uid == objection_id))
+				.ok_or(Error::<T>::ObjectionNotFound)?;
+			if upheld {
+				objection.status = ObjectionStatus::Upheld;
+				T::Currency::unreserve(&objection.objector, objection.deposit);
+			} else {
+				objection.status = ObjectionStatus::Dismissed;
+				let _ = T::Currency::slash_reserved(&objection.objector, objection.deposit);
+			}
+			Ok(())
+		})?;
+		if upheld {
+			Self::adjust_reputation(&upload.creator, false, T::UpheldObjectionPenalty::get());
+		}
+		Self::deposit_event(Event::ObjectionResolved { uid: upload_id, objection_id, upheld });
+		Ok(())
+	}
+
+	/// Settle the deposit of appeal `appeal_id` and, if it passed, move the upload on as if the
+	/// stage that rejected it had passed it.
+	fn resolve_appeal(appeal_id: u64, passed: bool) -> DispatchResult {
+		let mut appeal = Self::get_appeal(appeal_id).ok_or(Error::<T>::AppealNotFound)?;
+		if passed {
+			T::Currency::unreserve(&appeal.appellant, appeal.deposit);
+			Rejections::<T>::remove(appeal.upload_id);
+			// Overturned rejections don't count against the contributor. Rejections from
+			// before penalties were recorded are assumed to have cost the full penalty.
+			let penalty = RejectionPenalties::<T>::take(appeal.upload_id).unwrap_or_else(T::RejectedUploadPenalty::get);
+			Self::adjust_reputation(&appeal.appellant, true, penalty);
+			match appeal.stage {
+				UploadStatus::QualificationVoteInProgress => Self::start_verification_vote(appeal.upload_id)?,
+				UploadStatus::VerificationVoteInProgress => Self::start_expert_review(appeal.upload_id)?,
+				_ => Self::verify_upload(appeal.upload_id)?,
+			}
+		} else {
+			let _ = T::Currency::slash_reserved(&appeal.appellant, appeal.deposit);
+		}
+
+		appeal.status = Self::get_vote((VoteType::Appeal, appeal_id)).map_or(VoteStatus::Failed, |vote| vote.status);
+		Self::release_seats(appeal.upload_id, &appeal.panel);
+		Appeals::<T>::insert(appeal_id, &appeal);
+		Self::deposit_event(Event::AppealResolved { uid: appeal.upload_id, appeal_id, passed });
+		Ok(())
+	}
+
+	/// Open the council stage of a role application once its verification ballot passed.
+	fn open_council_ballot(vote_type: VoteType, uid: u64) -> DispatchResult {
+		let (start, end) = Self::ballot_window(vote_type);
+		Votes::<T>::insert((vote_type, uid), Vote::<T>::new(start, end));
+		Self::open_secret_ballot(vote_type, uid, end);
+		Self::deposit_event(Event::NewVote { vote_type, uid });
+		Ok(())
+	}
+
+	/// Give the applicant of role application `uid` its role and return their deposit. Applicants
+	/// who left the DAO, were demoted or were suspended in the meantime only get their deposit
+	/// back.
+	fn grant_role(uid: u64, role: Roles) -> Result<Option<T::AccountId>, DispatchError> {
+		let applicant = Self::get_role_application(uid).ok_or(Error::<T>::ApplicationNotFound)?;
+		let mut member = match Self::get_member(applicant.clone()) {
+			Some(member) => member,
+			None => {
+				Self::release_application_deposit(uid, false);
+				return Ok(None)
+			},
+		};
+		// Experts apply again for further domains.
+		let from_role = Self::role_requirement(role).from_role;
+		let eligible = member.role == from_role || (role == Roles::Expert && member.role == Roles::Expert);
+		if !eligible || Self::is_suspended(&applicant) {
+			Self::release_application_deposit(uid, false);
+			Self::deposit_event(Event::RoleApplicationLapsed { uid, who: applicant });
+			return Ok(None)
+		}
+		if role == Roles::Expert {
+			Self::add_expert(&applicant)?;
+		}
+		member.role = role;
+		Members::<T>::insert(&applicant, &member);
+		Self::release_application_deposit(uid, false);
+		Ok(Some(applicant))
+	}
+}
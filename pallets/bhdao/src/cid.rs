@@ -0,0 +1,90 @@
+//! Minimal CID parsing used by the offchain worker to check fetched content against the
+//! digest an upload was submitted with.
+//!
+//! Only sha2-256 multihashes are supported, in CIDv0 (`Qm...`, base58btc) and CIDv1 with the
+//! base32 multibase prefix (`b...`), which covers what IPFS produces by default.
+
+use sp_std::vec::Vec;
+
+/// Multihash code for sha2-256.
+const SHA2_256: u64 = 0x12;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Return the sha2-256 digest the given CID commits to, or `None` if the CID is malformed or
+/// uses another hash function.
+pub fn sha2_256_digest(cid: &[u8]) -> Option<[u8; 32]> {
+	let multihash = match cid {
+		[b'Q', b'm', ..] => decode_base58(cid)?,
+		[b'b', rest @ ..] => {
+			let bytes = decode_base32(rest)?;
+			let (version, rest) = read_varint(&bytes)?;
+			if version != 1 {
+				return None
+			}
+			// Skip the content codec, the digest covers the raw block whatever it encodes.
+			let (_codec, rest) = read_varint(rest)?;
+			rest.to_vec()
+		},
+		_ => return None,
+	};
+
+	let (code, rest) = read_varint(&multihash)?;
+	let (len, digest) = read_varint(rest)?;
+	if code != SHA2_256 || len != 32 || digest.len() != 32 {
+		return None
+	}
+
+	let mut out = [0u8; 32];
+	out.copy_from_slice(digest);
+	Some(out)
+}
+
+fn read_varint(input: &[u8]) -> Option<(u64, &[u8])> {
+	let mut value: u64 = 0;
+	for (i, byte) in input.iter().enumerate().take(9) {
+		value |= u64::from(byte & 0x7f) << (7 * i);
+		if byte & 0x80 == 0 {
+			return Some((value, &input[i + 1..]))
+		}
+	}
+	None
+}
+
+fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+	let mut bytes: Vec<u8> = Vec::new();
+	for c in input {
+		let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+		for byte in bytes.iter_mut().rev() {
+			carry += (*byte as u32) * 58;
+			*byte = (carry & 0xff) as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.insert(0, (carry & 0xff) as u8);
+			carry >>= 8;
+		}
+	}
+	let zeros = input.iter().take_while(|c| **c == b'1').count();
+	let mut out = sp_std::vec![0u8; zeros];
+	out.extend(bytes);
+	Some(out)
+}
+
+fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+	let mut out = Vec::with_capacity(input.len() * 5 / 8);
+	let mut buffer: u32 = 0;
+	let mut bits = 0;
+	for c in input {
+		let value = BASE32_ALPHABET.iter().position(|a| a == c)? as u32;
+		buffer = (buffer << 5) | value;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			out.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	Some(out)
+}
@@ -5,6 +5,10 @@
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 
+mod ballot;
+mod cid;
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -14,14 +18,57 @@ mod tests;
 //#[cfg(feature = "runtime-benchmarks")]
 //mod benchmarking;
 
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the keys the offchain worker signs content checks with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"bhda");
+
+/// The sr25519 application key of content attesters.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct AttesterId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AttesterId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature> for AttesterId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{pallet_prelude::*,traits::Currency,};
-	use frame_system::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, Currency, LockIdentifier, LockableCurrency, Randomness, ReservableCurrency, WithdrawReasons},
+	};
+	use frame_system::{
+		offchain::{AppCrypto, SendTransactionTypes, SendUnsignedTransaction, SignedPayload, Signer, SigningTypes},
+		pallet_prelude::*,
+	};
+	use pallet_bhdao_ballots::BallotHandler;
 	use scale_info::{
 		TypeInfo,
 	};
-	use sp_runtime::{ArithmeticError,traits::{CheckedAdd,One}};
+	use sp_runtime::{
+		ArithmeticError,
+		offchain::{http, storage::StorageValueRef, Duration},
+		traits::{CheckedAdd,Hash,IdentifyAccount,One,Saturating,Zero},
+		Perbill, SaturatedConversion,
+	};
 	use sp_std::{
 		vec,
 		vec::Vec,
@@ -33,6 +80,20 @@ pub mod pallet {
 
 	type BalanceOf<T> = <T as pallet_nft::Config>::Balance;
 	type TokenIdOf<T> = <T as pallet_nft::Config>::TokenId;
+	type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Lock placed on balances committed to conviction ballots.
+	const VOTING_LOCK_ID: LockIdentifier = *b"bhdaovot";
+
+	/// How long the offchain worker waits for the content gateway.
+	const FETCH_TIMEOUT_MS: u64 = 10_000;
+	/// Largest raw block the offchain worker downloads.
+	const MAX_CONTENT_SIZE: usize = 2 * 1024 * 1024;
+	/// Blocks to wait before the offchain worker checks the same upload again.
+	const ATTESTATION_RETRY_BLOCKS: u32 = 10;
+	/// Upload ids the offchain worker scans for Verified uploads to audit in one run.
+	const AUDIT_SCAN_LIMIT: u64 = 64;
 
 	#[pallet::type_value]
 	pub fn ContributorTokenShare<T: Config>() -> BalanceOf<T>
@@ -62,16 +123,84 @@ pub mod pallet {
 		pub creator: T::AccountId,
 		pub hash: Vec<u8>,
 		pub status: UploadStatus,
+		/// Subject categories, as expertise domain ids.
+		pub categories: Vec<u32>,
 	}
 
+	pub use pallet_bhdao_ballots::{Ballot, BallotStatus as VoteStatus, Tally, VoteChoice};
+
+	/// Ballots are timed in blocks of this chain.
+	pub type Vote<T> = Ballot<<T as frame_system::Config>::BlockNumber>;
+
+	/// A correction to a Verified upload, put to a `VoteType::Amendment` ballot.
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
 	#[scale_info(skip_type_params(T))]
-	pub struct Vote<T:Config> {
-		pub yes_votes: u64,
-		pub no_votes: u64,
-		pub start: T::BlockNumber,
-		pub end: T::BlockNumber,
+	pub struct Amendment<T:Config> {
+		pub upload_id: u64,
+		pub proposer: T::AccountId,
+		/// Hash of the corrected content.
+		pub hash: Vec<u8>,
+		pub reason: Vec<u8>,
+		pub status: VoteStatus,
+	}
+
+	/// A request to redact an upload, put to a `VoteType::Takedown` ballot of Experts.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Takedown<T:Config> {
+		pub upload_id: u64,
+		pub requester: T::AccountId,
+		/// Hash of the off-chain request, so no personal details are kept in state.
+		pub reason: T::Hash,
 		pub status: VoteStatus,
+		/// Whether the request froze the upload's token. A rejection only thaws a token the
+		/// request froze.
+		pub froze_token: bool,
+	}
+
+	/// A curated group of uploads, such as a family's letters or an oral-history series.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Collection<T:Config> {
+		pub curator: T::AccountId,
+		pub description: Vec<u8>,
+		/// Member uploads in the curator's order.
+		pub uploads: Vec<u64>,
+		pub status: CollectionStatus,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum CollectionStatus {
+		Open,
+		/// Its `InCollection` uploads are on a `VoteType::CollectionReview` ballot.
+		UnderReview,
+	}
+
+	/// Terms a document is published under, from the license registry.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum License<Hash> {
+		CcBy,
+		CcBySa,
+		CcByNd,
+		CcByNc,
+		CcByNcSa,
+		CcByNcNd,
+		Cc0,
+		AllRightsReserved,
+		/// Hash of the text of a custom license.
+		Custom(Hash),
+	}
+
+	/// One entry in the version history of a Verified upload.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct UploadVersion<T:Config> {
+		pub hash: Vec<u8>,
+		/// Amendment the version came from, `None` for the verified original.
+		pub amendment: Option<u64>,
+		pub at: T::BlockNumber,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -79,6 +208,16 @@ pub mod pallet {
 	pub struct Objection<T:Config> {
 		pub objector: T::AccountId,
 		pub hash: Vec<u8>,
+		/// Uid of the `VoteType::Objection` ballot on which Experts uphold or dismiss it.
+		pub uid: u64,
+		pub category: ObjectionCategory,
+		/// Hashes of the evidence backing the objection.
+		pub evidence: Vec<Vec<u8>>,
+		/// Hash of the contributor's response.
+		pub response: Option<Vec<u8>>,
+		/// Reserved from the objector, returned if upheld and slashed if dismissed.
+		pub deposit: NativeBalanceOf<T>,
+		pub status: ObjectionStatus,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -87,6 +226,109 @@ pub mod pallet {
 		pub start: T::BlockNumber,
 		pub end: T::BlockNumber,
 		pub objections: Option<Vec<Objection<T>>>,
+		/// Experts assigned to review the upload. Only they can raise objections.
+		pub panel: Vec<T::AccountId>,
+		/// Panelists who raised an objection or signed off.
+		pub responded: Vec<T::AccountId>,
+	}
+
+	/// Outcome of a gateway check, signed by the attester that ran it.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	pub struct ContentCheck<Public> {
+		pub upload_id: u64,
+		pub available: bool,
+		pub intact: bool,
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for ContentCheck<T::Public> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ContentAttestation<T:Config> {
+		/// The gateway served the content for the upload's CID.
+		pub available: bool,
+		/// The sha2-256 of the served content matches the digest in the CID.
+		pub intact: bool,
+		pub checked_at: T::BlockNumber,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ContentAudit<T:Config> {
+		pub last_audited: T::BlockNumber,
+		pub last_seen_available: Option<T::BlockNumber>,
+		pub consecutive_failures: u32,
+		/// Set after `MaxAuditFailures` failed audits in a row, cleared by a successful one.
+		pub at_risk: bool,
+	}
+
+	/// Voting power delegated to a member, or withheld from it on one ballot.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy, Default)]
+	pub struct DelegatedPower {
+		pub delegators: u32,
+		/// Sum of the delegators' reputation weights when they delegated.
+		pub reputation: u128,
+	}
+
+	impl DelegatedPower {
+		pub fn saturating_add(self, other: Self) -> Self {
+			DelegatedPower {
+				delegators: self.delegators.saturating_add(other.delegators),
+				reputation: self.reputation.saturating_add(other.reputation),
+			}
+		}
+
+		pub fn saturating_sub(self, other: Self) -> Self {
+			DelegatedPower {
+				delegators: self.delegators.saturating_sub(other.delegators),
+				reputation: self.reputation.saturating_sub(other.reputation),
+			}
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Delegation<T:Config> {
+		pub delegate: T::AccountId,
+		pub power: DelegatedPower,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Rejection<T:Config> {
+		/// Status the upload had when it was rejected.
+		pub stage: UploadStatus,
+		pub at: T::BlockNumber,
+		pub appealed: bool,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Appeal<T:Config> {
+		pub upload_id: u64,
+		pub appellant: T::AccountId,
+		/// Stage the upload was rejected at.
+		pub stage: UploadStatus,
+		pub deposit: NativeBalanceOf<T>,
+		/// Experts drawn to decide the appeal.
+		pub panel: Vec<T::AccountId>,
+		pub filed: T::BlockNumber,
+		pub status: VoteStatus,
+	}
+
+	/// Commit-reveal bookkeeping for a ballot opened while its vote type was in commit-reveal mode.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SecretBallot<T:Config> {
+		/// Last block of the reveal window, which follows the ballot's voting window.
+		pub reveal_end: T::BlockNumber,
+		pub commits: u32,
+		pub reveals: u32,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -98,14 +340,46 @@ pub mod pallet {
 		pub approved_contributions: u32,
 		pub role: Roles,
 		pub joined: T::BlockNumber,
+		/// Penalty points, each taking one off the member's reputation weight.
+		pub penalties: u32,
 	}
 
-	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
-	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-	pub enum VoteStatus {
-		InProgress,
-		Passed,
-		Failed,
+	/// Action on a member put to a `VoteType::Proposal` ballot.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub enum MemberAction<T:Config> {
+		/// Move the member down to a lower role.
+		Demote(Roles),
+		/// Suspend the member, until the given block or indefinitely.
+		Suspend(Option<T::BlockNumber>),
+		/// Remove the member from the DAO.
+		Remove,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct MemberProposal<T:Config> {
+		pub proposer: T::AccountId,
+		pub target: T::AccountId,
+		pub action: MemberAction<T>,
+		pub reason: Vec<u8>,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Reputation<T:Config> {
+		pub score: u32,
+		/// Block decay was last applied up to.
+		pub updated: T::BlockNumber,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Suspension<T:Config> {
+		pub reason: Vec<u8>,
+		pub since: T::BlockNumber,
+		/// Block the suspension lapses at, `None` until it is lifted.
+		pub until: Option<T::BlockNumber>,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
@@ -118,6 +392,14 @@ pub mod pallet {
 		CouncilVoteInProgress,
 		Verified,
 		Rejected,
+		/// Waiting to be reviewed together with the rest of its collection.
+		InCollection,
+		/// Waiting for every co-contributor to accept before Qualification opens.
+		AwaitingCoContributors,
+		/// Taken down for privacy. Only a tombstone hash of the content is kept.
+		Redacted,
+		/// Withdrawn before review because a co-contributor declined or the creator cancelled.
+		Withdrawn,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
@@ -130,6 +412,30 @@ pub mod pallet {
 		ExpertVerification,
 		ExpertCouncilApproval,
 		Proposal,
+		Objection,
+		Appeal,
+		CollectorVerification,
+		CollectorCouncilApproval,
+		Amendment,
+		CollectionReview,
+		Takedown,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ObjectionCategory {
+		Authenticity,
+		Copyright,
+		Privacy,
+		FactualError,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ObjectionStatus {
+		Pending,
+		Upheld,
+		Dismissed,
 	}
 
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
@@ -143,17 +449,232 @@ pub mod pallet {
 		Collector = 5,
 	}
 
+	/// Timing of the ballots of a `VoteType`.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct BallotTiming {
+		/// Blocks the ballot is open for.
+		pub window: u32,
+		/// Blocks between opening the ballot and the start of voting.
+		pub delay: u32,
+		/// A vote in the last `late_period` blocks pushes the end back by `extension` blocks,
+		/// at most `max_extensions` times per ballot.
+		pub late_period: u32,
+		pub extension: u32,
+		pub max_extensions: u32,
+	}
+
+	/// What a member needs before moving into a role.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct RoleRequirement {
+		/// Role the member has to hold first.
+		pub from_role: Roles,
+		/// Blocks since joining the DAO.
+		pub min_tenure: u32,
+		pub min_reputation: u32,
+		pub min_approved_contributions: u32,
+		/// Blocks since the member's last role application.
+		pub cooldown: u32,
+	}
+
+	/// A role requirement a member does not meet yet, with how much is missing.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum UnmetRequirement {
+		Role(Roles),
+		Tenure(u32),
+		Reputation(u32),
+		ApprovedContributions(u32),
+		Cooldown(u32),
+	}
+
+	/// How the ballots of a `VoteType` are weighted.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum VoteWeighting {
+		OneMemberOneVote,
+		/// Weight is the balance locked behind the ballot times its conviction.
+		Conviction,
+		/// Weight grows with approved contributions and voting history.
+		Reputation,
+	}
+
+	impl Default for VoteWeighting {
+		fn default() -> Self {
+			VoteWeighting::OneMemberOneVote
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum Conviction {
+		/// A tenth of the balance counts, locked until the ballot ends.
+		None,
+		Locked1x,
+		Locked2x,
+		Locked3x,
+		Locked4x,
+		Locked5x,
+		Locked6x,
+	}
+
+	impl Conviction {
+		/// Voting windows the balance stays locked for after the ballot ends.
+		pub fn lock_windows(self) -> u32 {
+			match self {
+				Conviction::None => 0,
+				Conviction::Locked1x => 1,
+				Conviction::Locked2x => 2,
+				Conviction::Locked3x => 3,
+				Conviction::Locked4x => 4,
+				Conviction::Locked5x => 5,
+				Conviction::Locked6x => 6,
+			}
+		}
+
+		pub fn weight(self, balance: u128) -> u128 {
+			match self {
+				Conviction::None => balance / 10,
+				_ => balance.saturating_mul(self.lock_windows() as u128),
+			}
+		}
+	}
+
+	/// The in-code storage version, see `migrations`.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+
 	#[pallet::pallet]
     #[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_nft::Config {
+	pub trait Config: frame_system::Config + pallet_nft::Config + SendTransactionTypes<Call<Self>> + SigningTypes {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Native currency locked by conviction ballots.
+		type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>
+			+ ReservableCurrency<Self::AccountId>;
 		#[pallet::constant]
 		type VotingWindow: Get<u32>;
+		/// Key the offchain worker signs its content checks with.
+		type AttesterId: AppCrypto<Self::Public, Self::Signature>;
+		/// Priority of the unsigned content attestations submitted by the offchain worker.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Length in blocks of a content audit epoch.
+		#[pallet::constant]
+		type AuditPeriod: Get<u32>;
+		/// Number of Verified uploads the offchain worker re-checks each audit epoch.
+		#[pallet::constant]
+		type AuditSampleSize: Get<u32>;
+		/// Consecutive failed audits after which an upload is marked at risk.
+		#[pallet::constant]
+		type MaxAuditFailures: Get<u32>;
+		/// Open Qualification or Verification ballots a member can have voted on at once.
+		#[pallet::constant]
+		type MaxOpenBallots: Get<u32>;
+		/// Members who can delegate to the same delegate for a vote type.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+		/// Length in blocks of the reveal window that follows a commit-reveal ballot.
+		#[pallet::constant]
+		type RevealWindow: Get<u32>;
+		/// Deposit reserved from an Expert raising an objection.
+		#[pallet::constant]
+		type ObjectionDeposit: Get<NativeBalanceOf<Self>>;
+		/// Evidence hashes an objection can carry.
+		#[pallet::constant]
+		type MaxObjectionEvidence: Get<u32>;
+		/// Source of randomness for drawing Expert panels.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// Deposit reserved from a contributor appealing a rejection.
+		#[pallet::constant]
+		type AppealDeposit: Get<NativeBalanceOf<Self>>;
+		/// Blocks after a rejection during which it can be appealed.
+		#[pallet::constant]
+		type AppealPeriod: Get<u32>;
+		/// Experts drawn for an appeal panel.
+		#[pallet::constant]
+		type AppealPanelSize: Get<u32>;
+		/// Experts drawn to review each upload.
+		#[pallet::constant]
+		type ReviewPanelSize: Get<u32>;
+		/// Most members the DAO can have in the Expert role at once.
+		#[pallet::constant]
+		type MaxExperts: Get<u32>;
+		/// Subject categories an upload can be tagged with.
+		#[pallet::constant]
+		type MaxCategories: Get<u32>;
+		/// Most uploads a collection can hold.
+		#[pallet::constant]
+		type MaxCollectionSize: Get<u32>;
+		/// Most co-contributors an upload can list besides its creator.
+		#[pallet::constant]
+		type MaxCoContributors: Get<u32>;
+		/// Most named subjects a sensitive upload can list.
+		#[pallet::constant]
+		type MaxSubjects: Get<u32>;
+		/// Expert KYC attestations a Collector application needs before the council can approve it.
+		#[pallet::constant]
+		type MinKycAttestations: Get<u32>;
+		/// Blocks after verification during which only Collectors have access to an upload's NFT.
+		#[pallet::constant]
+		type CollectorEarlyAccess: Get<u32>;
+		/// Reputation for voting with the outcome of a ballot.
+		#[pallet::constant]
+		type AccurateVoteReward: Get<u32>;
+		/// Votes settled into reputation by the call finalizing a ballot. The rest are settled
+		/// in idle blocks.
+		#[pallet::constant]
+		type MaxSettlements: Get<u32>;
+		/// Reputation for a Verified upload.
+		#[pallet::constant]
+		type VerifiedUploadReward: Get<u32>;
+		/// Reputation lost for a rejected upload.
+		#[pallet::constant]
+		type RejectedUploadPenalty: Get<u32>;
+		/// Reputation lost for each upheld objection against an upload.
+		#[pallet::constant]
+		type UpheldObjectionPenalty: Get<u32>;
+		/// Reputation at which a Qualifier becomes a Contributor.
+		#[pallet::constant]
+		type ContributorThreshold: Get<u32>;
+		/// Blocks per reputation decay step.
+		#[pallet::constant]
+		type ReputationDecayPeriod: Get<u32>;
+		/// Share of reputation lost at every decay step.
+		#[pallet::constant]
+		type ReputationDecay: Get<Perbill>;
+		/// Share of the ayes and nays on an Expert or council ballot that `NoWithVeto` votes
+		/// need to veto it.
+		#[pallet::constant]
+		type VetoThreshold: Get<Perbill>;
+		/// Reserved from role applicants, and slashed if the council vetoes the application.
+		#[pallet::constant]
+		type ApplicationDeposit: Get<NativeBalanceOf<Self>>;
+		/// Reserved from the proposer of a member action, and slashed if the ballot fails.
+		#[pallet::constant]
+		type MemberActionDeposit: Get<NativeBalanceOf<Self>>;
+		/// Share of the Experts that must turn out, abstentions included, for a member action
+		/// to pass.
+		#[pallet::constant]
+		type MemberActionQuorum: Get<Perbill>;
+		/// Reserved from the proposer of an amendment, and slashed if the amendment fails.
+		#[pallet::constant]
+		type AmendmentDeposit: Get<NativeBalanceOf<Self>>;
+		/// Most amendments that can be proposed for an upload.
+		#[pallet::constant]
+		type MaxAmendments: Get<u32>;
+		/// Blocks after a rejected takedown during which no new takedown of the upload can be
+		/// requested.
+		#[pallet::constant]
+		type TakedownCooldown: Get<u32>;
+		/// Reserved from the requester of a takedown, and slashed if the Experts veto it.
+		#[pallet::constant]
+		type TakedownDeposit: Get<NativeBalanceOf<Self>>;
 	}
 
 	#[pallet::storage]
@@ -232,590 +753,4177 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_role_application)]
-	pub(super) type ExpertApplication<T:Config> = StorageMap<
+	#[pallet::getter(fn objection_uid_count)]
+	pub(super) type ObjectionCount<T> = StorageValue<_, u64,ValueQuery>;
+
+	/// Upload each objection was raised against.
+	#[pallet::storage]
+	#[pallet::getter(fn get_objection_upload)]
+	pub(super) type ObjectionUploads<T> = StorageMap<
 		_,
 		Blake2_128Concat,
 		u64,
-		T::AccountId,
+		u64,
 		OptionQuery,
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_vote)]
-	pub(super) type Votes<T:Config> = StorageMap<
+	#[pallet::getter(fn get_rejection)]
+	pub(super) type Rejections<T:Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		(VoteType,u64),
-		Vote<T>,
+		u64,
+		Rejection<T>,
 		OptionQuery,
 	>;
 
+	/// Reputation the contributor actually lost when an upload was rejected, given back if the
+	/// rejection is overturned.
 	#[pallet::storage]
-	#[pallet::getter(fn get_member_vote)]
-	pub(super) type CheckVote<T:Config> = StorageMap<
+	#[pallet::getter(fn get_rejection_penalty)]
+	pub(super) type RejectionPenalties<T> = StorageMap<
 		_,
 		Blake2_128Concat,
-		(T::AccountId,VoteType,u64),
-		bool,
+		u64,
+		u32,
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn appeal_uid_count)]
+	pub(super) type AppealCount<T> = StorageValue<_, u64,ValueQuery>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		MemberAdded{who: T::AccountId,uid: u32},
-		NewUpload{uid: u64},
-		NewVote{vote_type: VoteType, uid: u64},
-		VoteCast{vote_type: VoteType, uid: u64},
-		VoteEnded{vote_type: VoteType, uid: u64},
-		ExpertReviewStarted{uid: u64},
-		ExpertReviewEnded{uid: u64},
-		ObjectionRaised{uid: u64, who: T::AccountId},
-	}
+	#[pallet::storage]
+	#[pallet::getter(fn get_appeal)]
+	pub(super) type Appeals<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Appeal<T>,
+		OptionQuery,
+	>;
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Member Already Exists
-		MemberAlreadyExists,
-		/// Not A Member
-		NotAMember,
-		/// Not Eligible To Contribute
-		NotEligibleToContribute,
-		// Not Eligible To Verify
-		NotEligibleToVerify,
-		/// Vote Not Found
-		VoteNotFound,
-		/// Vote Not In Progress
-		VoteNotInProgress,
-		/// Voting Window Not Valid
-		VotingWindowNotValid,
-		/// Vote Still In Progress
-		VoteStillInProgress,
-		/// Upload Not Found
-		UploadNotFound,
-		/// Not Eligible For Expert Role
-		NotEligibleForExpertRole,
-		/// Wrong Vote Type
-		WrongVoteType,
-		/// Not An Expert
-		NotAnExpert,
-		/// WrongRoleAssigned
-		WrongRoleAssigned,
-		/// Wrong Role Applied
-		WrongRoleApplied,
-		/// Not Eligible For Verifier Role
-		NotEligibleForVerifierRole,
-		/// NotUnderExpertReview
-		NotUnderExpertReview,
-		
-	}
+	/// Every appeal filed against an upload, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_appeals)]
+	pub(super) type UploadAppeals<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<u64>,
+		ValueQuery,
+	>;
 
-	
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		
-		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2).ref_time())]
+	/// KYC metadata of Collector applications.
+	#[pallet::storage]
+	#[pallet::getter(fn get_collector_application)]
+	pub(super) type CollectorApplications<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<u8>,
+		OptionQuery,
+	>;
+
+	/// Experts who attested the KYC metadata of a Collector application.
+	#[pallet::storage]
+	#[pallet::getter(fn has_attested_kyc)]
+	pub(super) type KycAttestations<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Funds Collectors reserved behind uploads still in review, paid to the contributor on verification.
+	#[pallet::storage]
+	#[pallet::getter(fn get_sponsorship)]
+	pub(super) type Sponsorships<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		NativeBalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Block until which only Collectors have access to a Verified upload's NFT.
+	#[pallet::storage]
+	#[pallet::getter(fn get_early_access)]
+	pub(super) type EarlyAccess<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TokenIdOf<T>,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// Contributors a member declared a conflict of interest with.
+	#[pallet::storage]
+	#[pallet::getter(fn has_conflict)]
+	pub(super) type Conflicts<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn domain_uid_count)]
+	pub(super) type DomainCount<T> = StorageValue<_, u32,ValueQuery>;
+
+	/// Names of the expertise domains, which double as upload subject categories.
+	#[pallet::storage]
+	#[pallet::getter(fn get_domain)]
+	pub(super) type Domains<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Vec<u8>,
+		OptionQuery,
+	>;
+
+	/// Every member in the Expert role, so panels are drawn without walking all members.
+	#[pallet::storage]
+	#[pallet::getter(fn get_experts)]
+	pub(super) type Experts<T:Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Uploads whose review or appeal panel an Expert sits on until it is decided.
+	#[pallet::storage]
+	#[pallet::getter(fn get_panel_seats)]
+	pub(super) type PanelSeats<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Vec<u64>,
+		ValueQuery,
+	>;
+
+	/// Experts by domain.
+	#[pallet::storage]
+	#[pallet::getter(fn is_domain_expert)]
+	pub(super) type ExpertDomains<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Domain claimed by an Expert role application.
+	#[pallet::storage]
+	#[pallet::getter(fn get_application_domain)]
+	pub(super) type ApplicationDomains<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		u32,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_role_application)]
+	pub(super) type ExpertApplication<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_vote)]
+	pub(super) type Votes<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		Vote<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_member_vote)]
+	pub(super) type CheckVote<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId,VoteType,u64),
+		bool,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_vote_weighting)]
+	pub(super) type VoteWeightings<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VoteType,
+		VoteWeighting,
+		ValueQuery,
+	>;
+
+	/// Block after which an account's conviction lock can be removed.
+	#[pallet::storage]
+	#[pallet::getter(fn get_voting_lock_expiry)]
+	pub(super) type VotingLockExpiry<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_delegation)]
+	pub(super) type Delegations<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		VoteType,
+		Delegation<T>,
+		OptionQuery,
+	>;
+
+	/// Total power delegated to a member for a vote type.
+	#[pallet::storage]
+	#[pallet::getter(fn get_delegated_power)]
+	pub(super) type DelegatedPowers<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		VoteType,
+		DelegatedPower,
+		ValueQuery,
+	>;
+
+	/// Power of a delegate's delegators who voted directly on a ballot before the delegate did.
+	#[pallet::storage]
+	#[pallet::getter(fn get_overridden_power)]
+	pub(super) type OverriddenPower<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		Blake2_128Concat,
+		T::AccountId,
+		DelegatedPower,
+		ValueQuery,
+	>;
+
+	/// Ballots still open that a member voted on, with the side they took and the delegated
+	/// weight applied on them. Lets delegation changes update tallies by walking the member's
+	/// own bounded list instead of delegators.
+	#[pallet::storage]
+	#[pallet::getter(fn get_open_ballots)]
+	pub(super) type OpenBallots<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		VoteType,
+		Vec<(u64,VoteChoice,u128)>,
+		ValueQuery,
+	>;
+
+	/// Latest block a ballot a member voted on without it fitting in their `OpenBallots` can
+	/// still be voted on. The member can't delegate before then.
+	#[pallet::storage]
+	#[pallet::getter(fn get_untracked_ballots_end)]
+	pub(super) type UntrackedBallotsEnd<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		VoteType,
+		T::BlockNumber,
+		ValueQuery,
+	>;
+
+	/// Members delegating to a delegate for a vote type, at most `MaxDelegators`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_delegators)]
+	pub(super) type Delegators<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		VoteType,
+		Vec<T::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Vote types whose new ballots are cast as commitments and revealed afterwards.
+	#[pallet::storage]
+	#[pallet::getter(fn is_commit_reveal)]
+	pub(super) type CommitReveal<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VoteType,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_secret_ballot)]
+	pub(super) type SecretBallots<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		SecretBallot<T>,
+		OptionQuery,
+	>;
+
+	/// Commitments not revealed yet. Whatever is left when the ballot is finalized went unrevealed.
+	#[pallet::storage]
+	#[pallet::getter(fn get_vote_commitment)]
+	pub(super) type VoteCommitments<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		Blake2_128Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// Commitments a member failed to reveal.
+	#[pallet::storage]
+	#[pallet::getter(fn get_missed_reveals)]
+	pub(super) type MissedReveals<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_uid_count)]
+	pub(super) type ProposalCount<T> = StorageValue<_, u64,ValueQuery>;
+
+	/// Member actions awaiting their `VoteType::Proposal` ballot.
+	#[pallet::storage]
+	#[pallet::getter(fn get_member_proposal)]
+	pub(super) type MemberProposals<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		MemberProposal<T>,
+		OptionQuery,
+	>;
+
+	/// Reputation as of its last update. Read it through `reputation_of` to apply decay.
+	#[pallet::storage]
+	#[pallet::getter(fn get_reputation)]
+	pub(super) type Reputations<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Reputation<T>,
+		OptionQuery,
+	>;
+
+	/// Votes cast on open ballots, settled into reputation once the ballot is finalized.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ballot_vote)]
+	pub(super) type BallotVotes<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		Blake2_128Concat,
+		T::AccountId,
+		VoteChoice,
+		OptionQuery,
+	>;
+
+	/// Finalized ballots with the outcome their `BallotVotes` are still to be settled against,
+	/// oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_settlements)]
+	pub(super) type PendingSettlements<T> = StorageValue<_, Vec<(VoteType,u64,bool)>, ValueQuery>;
+
+	/// Hash of the rationale a voter published for their vote on a ballot.
+	#[pallet::storage]
+	#[pallet::getter(fn get_rationale)]
+	pub(super) type Rationales<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		Blake2_128Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from the applicant of a role application.
+	#[pallet::storage]
+	#[pallet::getter(fn get_application_deposit)]
+	pub(super) type ApplicationDeposits<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(T::AccountId, NativeBalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from the proposer of a member action.
+	#[pallet::storage]
+	#[pallet::getter(fn get_member_action_deposit)]
+	pub(super) type MemberActionDeposits<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(T::AccountId, NativeBalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from the proposer of an amendment.
+	#[pallet::storage]
+	#[pallet::getter(fn get_amendment_deposit)]
+	pub(super) type AmendmentDeposits<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(T::AccountId, NativeBalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Requirements set by governance. Roles without an entry use `default_requirement`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_role_requirement)]
+	pub(super) type RoleRequirements<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Roles,
+		RoleRequirement,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_application)]
+	pub(super) type LastApplication<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// Ballot timing set by governance. Vote types without an entry use `T::VotingWindow`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ballot_timing)]
+	pub(super) type BallotTimings<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VoteType,
+		BallotTiming,
+		OptionQuery,
+	>;
+
+	/// Length of expert review set by governance, `T::VotingWindow` if unset.
+	#[pallet::storage]
+	#[pallet::getter(fn get_expert_review_window)]
+	pub(super) type ExpertReviewWindow<T> = StorageValue<_, u32,OptionQuery>;
+
+	/// Times a ballot's end was pushed back for late votes.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ballot_extensions)]
+	pub(super) type BallotExtensions<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(VoteType,u64),
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_suspension)]
+	pub(super) type Suspensions<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Suspension<T>,
+		OptionQuery,
+	>;
+
+	/// Accounts removed by a member-action ballot, with the block they were removed in. They
+	/// can't join again, only be readmitted by root.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ban)]
+	pub(super) type Banned<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// Token minted for a Verified upload.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_token)]
+	pub(super) type UploadTokens<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		TokenIdOf<T>,
+		OptionQuery,
+	>;
+
+	/// Every version of a Verified upload, oldest first. The last one is canonical.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_versions)]
+	pub(super) type UploadVersions<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<UploadVersion<T>>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn amendment_uid_count)]
+	pub type AmendmentCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_amendment)]
+	pub(super) type Amendments<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Amendment<T>,
+		OptionQuery,
+	>;
+
+	/// Every amendment proposed for an upload, whatever became of it.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_amendments)]
+	pub(super) type UploadAmendments<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<u64>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_uid_count)]
+	pub type CollectionCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Collections, sharing their ids with the matching pallet_nft collections.
+	#[pallet::storage]
+	#[pallet::getter(fn get_collection)]
+	pub(super) type Collections<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Collection<T>,
+		OptionQuery,
+	>;
+
+	/// Collection an upload belongs to. An upload is in at most one collection.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_collection)]
+	pub(super) type UploadCollection<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		u64,
+		OptionQuery,
+	>;
+
+	/// Collections the creator of an upload agreed to let its curator add the upload to.
+	#[pallet::storage]
+	#[pallet::getter(fn has_collection_consent)]
+	pub(super) type CollectionConsents<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		u64,
+		(),
+		OptionQuery,
+	>;
+
+	/// Co-contributors of an upload with their share in basis points. The creator keeps the rest.
+	#[pallet::storage]
+	#[pallet::getter(fn get_co_contributors)]
+	pub(super) type CoContributors<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<(T::AccountId,u16)>,
+		ValueQuery,
+	>;
+
+	/// Co-contributors who have not yet accepted their part in an upload.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_co_contributors)]
+	pub(super) type PendingCoContributors<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<T::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Living people or estates named in a sensitive upload, whose consent it needs.
+	#[pallet::storage]
+	#[pallet::getter(fn get_subjects)]
+	pub(super) type SensitiveUploads<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Vec<T::AccountId>,
+		OptionQuery,
+	>;
+
+	/// Hash of the consent a subject gave for a sensitive upload.
+	#[pallet::storage]
+	#[pallet::getter(fn get_consent)]
+	pub(super) type Consents<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn takedown_uid_count)]
+	pub type TakedownCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_takedown)]
+	pub(super) type Takedowns<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Takedown<T>,
+		OptionQuery,
+	>;
+
+	/// Takedown under review for an upload. Only one can be open at a time.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_takedown)]
+	pub(super) type PendingTakedowns<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		u64,
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from the requester of a takedown.
+	#[pallet::storage]
+	#[pallet::getter(fn get_takedown_deposit)]
+	pub(super) type TakedownDeposits<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(T::AccountId, NativeBalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// Licenses contributors can choose from.
+	#[pallet::storage]
+	#[pallet::getter(fn get_license)]
+	pub(super) type Licenses<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		License<T::Hash>,
+		OptionQuery,
+	>;
+
+	/// Licenses that can no longer be chosen. Uploads already under them keep them.
+	#[pallet::storage]
+	#[pallet::getter(fn is_license_retired)]
+	pub(super) type RetiredLicenses<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		bool,
+		ValueQuery,
+	>;
+
+	/// License chosen for an upload.
+	#[pallet::storage]
+	#[pallet::getter(fn get_upload_license)]
+	pub(super) type UploadLicenses<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		u32,
+		OptionQuery,
+	>;
+
+	/// Upload and new license of a pallet_nft holder proposal to relicense its token.
+	#[pallet::storage]
+	#[pallet::getter(fn get_license_change)]
+	pub(super) type LicenseChanges<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(u64, u32),
+		OptionQuery,
+	>;
+
+	/// Hash of the content of a Redacted upload.
+	#[pallet::storage]
+	#[pallet::getter(fn get_tombstone)]
+	pub(super) type Tombstones<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// Block until which no new takedown of an upload can be requested, after one was rejected.
+	#[pallet::storage]
+	#[pallet::getter(fn get_takedown_cooldown)]
+	pub(super) type TakedownCooldowns<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// Amendment under review for an upload. Only one can be open at a time.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_amendment)]
+	pub(super) type PendingAmendments<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		u64,
+		OptionQuery,
+	>;
+
+	/// Base URL of the IPFS-compatible gateway the offchain worker fetches uploads from.
+	#[pallet::storage]
+	#[pallet::getter(fn get_content_gateway)]
+	pub(super) type ContentGateway<T> = StorageValue<_, Vec<u8>,ValueQuery>;
+
+	/// Accounts whose offchain worker keys may submit content checks.
+	#[pallet::storage]
+	#[pallet::getter(fn get_attesters)]
+	pub(super) type Attesters<T:Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Uploads in Qualification still waiting for their content attestation.
+	#[pallet::storage]
+	pub(super) type PendingAttestations<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_content_attestation)]
+	pub(super) type ContentAttestations<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		ContentAttestation<T>,
+		OptionQuery,
+	>;
+
+	/// Gateway check of the content an amendment proposes, by amendment id.
+	#[pallet::storage]
+	#[pallet::getter(fn get_amendment_attestation)]
+	pub(super) type AmendmentAttestations<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		ContentAttestation<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_content_audit)]
+	pub(super) type ContentAudits<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		ContentAudit<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Initial DAO members with their role and metadata.
+		pub members: Vec<(T::AccountId, Roles, Vec<u8>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { members: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (who, role, metadata) in &self.members {
+				assert!(!Members::<T>::contains_key(who), "Duplicate member in genesis");
+				assert!(*role != Roles::None, "Genesis member without a role");
+				let uid = MembersCount::<T>::get() + 1;
+
+				let member = Member::<T> {
+					member_id: uid,
+					metadata: metadata.clone(),
+					vote_count: 0,
+					approved_contributions: 0,
+					role: *role,
+					joined: Zero::zero(),
+					penalties: 0,
+				};
+
+				Members::<T>::insert(who, &member);
+				MembersCount::<T>::put(uid);
+				if *role == Roles::Expert {
+					Pallet::<T>::add_expert(who).expect("Too many Experts in genesis");
+				}
+			}
+		}
+	}
+
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		MemberAdded{who: T::AccountId,uid: u32},
+		NewUpload{uid: u64},
+		NewVote{vote_type: VoteType, uid: u64},
+		VoteCast{vote_type: VoteType, uid: u64},
+		VoteEnded{vote_type: VoteType, uid: u64, status: VoteStatus, tally: Tally},
+		ExpertReviewStarted{uid: u64},
+		ExpertReviewEnded{uid: u64},
+		ObjectionRaised{uid: u64, who: T::AccountId},
+		ObjectionResponded{uid: u64, objection_id: u64},
+		ObjectionResolved{uid: u64, objection_id: u64, upheld: bool},
+		UploadRejected{uid: u64, stage: UploadStatus},
+		AmendmentSubmitted{uid: u64, upload_id: u64, who: T::AccountId},
+		UploadAmended{uid: u64, amendment_id: u64, version: u32},
+		AmendmentRejected{uid: u64, upload_id: u64},
+		CollectionCreated{uid: u64, curator: T::AccountId},
+		AddedToCollection{uid: u64, upload_id: u64},
+		CollectionReordered{uid: u64},
+		CollectionSubmitted{uid: u64},
+		CollectionReviewed{uid: u64, passed: bool},
+		CoContributionAccepted{uid: u64, who: T::AccountId},
+		UploadMarkedSensitive{uid: u64, subjects: Vec<T::AccountId>},
+		ConsentAttested{uid: u64, who: T::AccountId},
+		TakedownRequested{uid: u64, upload_id: u64, who: T::AccountId},
+		UploadRedacted{uid: u64, takedown_id: u64, tombstone: T::Hash},
+		TakedownRejected{uid: u64, upload_id: u64},
+		LicenseRegistered{license_id: u32},
+		LicenseRetired{license_id: u32},
+		LicenseChangeProposed{uid: u64, proposal_id: u64, license: u32},
+		UploadLicenseChanged{uid: u64, license: u32},
+		LicenseChangeRejected{uid: u64, proposal_id: u64},
+		AppealFiled{uid: u64, appeal_id: u64, panel: Vec<T::AccountId>},
+		AppealResolved{uid: u64, appeal_id: u64, passed: bool},
+		ReviewPanelAssigned{uid: u64, panel: Vec<T::AccountId>},
+		ReviewSignedOff{uid: u64, who: T::AccountId},
+		PanelistPenalized{uid: u64, who: T::AccountId},
+		ConflictDeclared{who: T::AccountId, contributor: T::AccountId},
+		ConflictWithdrawn{who: T::AccountId, contributor: T::AccountId},
+		DomainAdded{domain: u32, name: Vec<u8>},
+		ExpertiseClaimed{uid: u64, who: T::AccountId, domain: u32},
+		ExpertiseGranted{who: T::AccountId, domain: u32},
+		ContentGatewaySet{gateway: Vec<u8>},
+		AttestersSet{attesters: Vec<T::AccountId>},
+		ContentAttested{uid: u64, available: bool, intact: bool},
+		ContentAudited{uid: u64, available: bool, intact: bool},
+		ContentUnavailable{uid: u64, failures: u32},
+		VoteWeightingSet{vote_type: VoteType, weighting: VoteWeighting},
+		ConvictionVoteCast{vote_type: VoteType, uid: u64, who: T::AccountId, weight: u128},
+		VotingBalanceUnlocked{who: T::AccountId},
+		Delegated{who: T::AccountId, delegate: T::AccountId, vote_type: VoteType},
+		Undelegated{who: T::AccountId, vote_type: VoteType},
+		CommitRevealSet{vote_type: VoteType, enabled: bool},
+		VoteCommitted{vote_type: VoteType, uid: u64, who: T::AccountId},
+		VoteRevealed{vote_type: VoteType, uid: u64, who: T::AccountId},
+		/// Commitments left unrevealed, counted as abstentions.
+		VotesUnrevealed{vote_type: VoteType, uid: u64, count: u32},
+		MemberActionProposed{uid: u64, target: T::AccountId, action: MemberAction<T>, reason: Vec<u8>},
+		MemberDemoted{who: T::AccountId, role: Roles},
+		MemberSuspended{who: T::AccountId, until: Option<T::BlockNumber>, reason: Vec<u8>},
+		SuspensionLifted{who: T::AccountId},
+		MemberRemoved{who: T::AccountId},
+		MemberResigned{who: T::AccountId},
+		CollectorApplied{uid: u64, who: T::AccountId},
+		KycAttested{uid: u64, who: T::AccountId},
+		CollectorApproved{who: T::AccountId},
+		UploadSponsored{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>},
+		/// A sponsorship was paid to the contributor or, if the upload was rejected, returned.
+		SponsorshipReleased{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>, paid: bool},
+		ReputationChanged{who: T::AccountId, score: u32},
+		MemberPromoted{who: T::AccountId, role: Roles},
+		RoleRequirementSet{role: Roles, requirement: RoleRequirement},
+		BallotTimingSet{vote_type: VoteType, timing: BallotTiming},
+		ExpertReviewWindowSet{window: u32},
+		BallotExtended{vote_type: VoteType, uid: u64, end: T::BlockNumber},
+		RationaleRecorded{vote_type: VoteType, uid: u64, who: T::AccountId, rationale: T::Hash},
+		ApplicationDepositSlashed{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>},
+		MemberActionDepositSlashed{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>},
+		AmendmentDepositSlashed{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>},
+		AmendmentAttested{uid: u64, upload_id: u64, available: bool, intact: bool},
+		CollectionConsentGiven{uid: u64, upload_id: u64},
+		CoContributionDeclined{uid: u64, who: T::AccountId},
+		UploadWithdrawn{uid: u64},
+		/// The applicant no longer held the role they applied from, or was suspended, when their
+		/// application passed.
+		RoleApplicationLapsed{uid: u64, who: T::AccountId},
+		TakedownDepositSlashed{uid: u64, who: T::AccountId, amount: NativeBalanceOf<T>},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Member Already Exists
+		MemberAlreadyExists,
+		/// Not A Member
+		NotAMember,
+		/// Not Eligible To Contribute
+		NotEligibleToContribute,
+		// Not Eligible To Verify
+		NotEligibleToVerify,
+		/// Vote Not Found
+		VoteNotFound,
+		/// Vote Not In Progress
+		VoteNotInProgress,
+		/// Voting Window Not Valid
+		VotingWindowNotValid,
+		/// Vote Still In Progress
+		VoteStillInProgress,
+		/// Upload Not Found
+		UploadNotFound,
+		/// Not Eligible For Expert Role
+		NotEligibleForExpertRole,
+		/// Wrong Vote Type
+		WrongVoteType,
+		/// Not An Expert
+		NotAnExpert,
+		/// The DAO already has `MaxExperts` Experts.
+		TooManyExperts,
+		/// WrongRoleAssigned
+		WrongRoleAssigned,
+		/// Wrong Role Applied
+		WrongRoleApplied,
+		/// Not Eligible For Verifier Role
+		NotEligibleForVerifierRole,
+		/// NotUnderExpertReview
+		NotUnderExpertReview,
+		/// Not In Qualification
+		NotInQualification,
+		/// Content Already Attested
+		ContentAlreadyAttested,
+		/// Not Verified
+		NotVerified,
+		/// Already Audited This Epoch
+		AlreadyAuditedThisEpoch,
+		/// Already Voted
+		AlreadyVoted,
+		/// Conviction Vote Required
+		ConvictionVoteRequired,
+		/// Not Conviction Weighted
+		NotConvictionWeighted,
+		/// Weighting Not Allowed For Council Ballots
+		WeightingNotAllowed,
+		/// Insufficient Balance
+		InsufficientBalance,
+		/// Zero Balance
+		ZeroBalance,
+		/// No Voting Lock
+		NoVotingLock,
+		/// Voting Lock Not Expired
+		VotingLockNotExpired,
+		/// Cannot Delegate To Self
+		CannotDelegateToSelf,
+		/// Delegate Not Eligible
+		DelegateNotEligible,
+		/// Not Delegating
+		NotDelegating,
+		/// Too Many Open Ballots
+		TooManyOpenBallots,
+		/// Commit Reveal Required
+		CommitRevealRequired,
+		/// Not A Commit Reveal Ballot
+		NotCommitReveal,
+		/// Reveal Window Not Valid
+		RevealWindowNotValid,
+		/// No Commitment
+		NoCommitment,
+		/// Commitment Mismatch
+		CommitmentMismatch,
+		/// Objection Not Found
+		ObjectionNotFound,
+		/// Too Much Evidence
+		TooMuchEvidence,
+		/// Objector Cannot Adjudicate
+		ObjectorCannotAdjudicate,
+		/// Not The Upload Creator
+		NotUploadCreator,
+		/// Already Responded
+		AlreadyResponded,
+		/// Upload Not Rejected
+		NotRejected,
+		/// Already Appealed
+		AlreadyAppealed,
+		/// Appeal Period Over
+		AppealPeriodOver,
+		/// No Eligible Experts
+		NoEligibleExperts,
+		/// Appeal Not Found
+		AppealNotFound,
+		/// Not On The Panel
+		NotOnPanel,
+		/// Already Responded To Review
+		AlreadyRespondedToReview,
+		/// No Conflict Declared
+		NoConflict,
+		/// Unknown Domain
+		UnknownDomain,
+		/// Domain Required For Expert Applications
+		DomainRequired,
+		/// Already An Expert In This Domain
+		AlreadyDomainExpert,
+		/// Too Many Categories
+		TooManyCategories,
+		/// Role Application Not Found
+		ApplicationNotFound,
+		/// Member Suspended
+		Suspended,
+		/// Member Not Suspended
+		NotSuspended,
+		/// Demotion Must Be To A Lower Role
+		InvalidDemotion,
+		/// Proposal Not Found
+		ProposalNotFound,
+		/// Not Eligible For Collector Role
+		NotEligibleForCollectorRole,
+		/// Not A Collector Application
+		NotACollectorApplication,
+		/// Cannot Attest Own Application
+		CannotAttestOwnApplication,
+		/// KYC Already Attested
+		AlreadyAttested,
+		/// Not A Collector
+		NotACollector,
+		/// Upload Not In Review
+		UploadNotInReview,
+		/// Role Requirements Not Met
+		RequirementsNotMet,
+		/// Voting Window Must Not Be Empty
+		InvalidTiming,
+		/// `NoWithVeto` is only open to Expert and council ballots.
+		VetoNotAllowed,
+		/// Only voters can record a rationale for their vote.
+		NotVoted,
+		/// The upload already has an amendment under review.
+		AmendmentPending,
+		AmendmentNotFound,
+		CollectionNotFound,
+		NotCurator,
+		/// Collections can only take the curator's own uploads and Verified ones.
+		CannotCollectUpload,
+		AlreadyInCollection,
+		CollectionFull,
+		CollectionUnderReview,
+		/// The collection has no uploads waiting for review.
+		NothingToReview,
+		/// The new order must list every upload of the collection once.
+		InvalidOrder,
+		TooManyCoContributors,
+		/// Co-contributors must be distinct from each other and from the creator, each with a
+		/// non-zero share, and leave the creator a share of their own.
+		InvalidCoContributors,
+		NotACoContributor,
+		/// Co-contributions can only be accepted, declined or cancelled before Qualification
+		/// starts.
+		NotAwaitingCoContributors,
+		TooManySubjects,
+		NotASubject,
+		/// The upload has left review.
+		UploadClosed,
+		TakedownPending,
+		TakedownNotFound,
+		/// A subject of the sensitive upload has not consented yet.
+		MissingConsent,
+		/// The license is not in the registry or has been retired.
+		UnknownLicense,
+		LicenseAlreadyRegistered,
+		LicenseChangeNotFound,
+		/// The account was removed from the DAO and can't join again.
+		AccountBanned,
+		TooManyDelegators,
+		TooManyAmendments,
+		/// A takedown of the upload was rejected too recently.
+		TakedownCoolingDown,
+		/// The upload was taken down.
+		UploadIsRedacted,
+		/// Only the creator and co-contributors of an upload can amend it.
+		NotUploadContributor,
+
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let per_vote = Self::settlement_weight();
+			// Reading and putting back `PendingSettlements`.
+			let base = T::DbWeight::get().reads_writes(1,1);
+			if Self::get_pending_settlements().is_empty() || remaining_weight.any_lt(base.saturating_add(per_vote)) {
+				return T::DbWeight::get().reads(1)
+			}
+			let limit = (remaining_weight.ref_time().saturating_sub(base.ref_time()) / per_vote.ref_time().max(1)).saturated_into::<u32>();
+			let settled = Self::settle_pending(limit);
+			base.saturating_add(per_vote.saturating_mul(settled.into()))
+		}
+
+		fn offchain_worker(block_number: T::BlockNumber) {
+			if let Err(e) = Self::attest_pending_uploads(block_number) {
+				log::error!(target: "runtime::bhdao", "Content attestation failed: {}", e);
+			}
+
+			if let Err(e) = Self::attest_pending_amendments(block_number) {
+				log::error!(target: "runtime::bhdao", "Amendment attestation failed: {}", e);
+			}
+
+			if let Err(e) = Self::audit_verified_uploads(block_number) {
+				log::error!(target: "runtime::bhdao", "Content audit failed: {}", e);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			// Content checks come from an offchain worker, never straight from a block.
+			match source {
+				TransactionSource::Local | TransactionSource::External => {},
+				_ => return InvalidTransaction::Call.into(),
+			}
+
+			match call {
+				Call::submit_content_attestation { check, signature } => {
+					Self::ensure_attestable(check, signature)?;
+
+					ValidTransaction::with_tag_prefix("BhdaoContentAttestation")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(check.upload_id)
+						.longevity(ATTESTATION_RETRY_BLOCKS.into())
+						.propagate(true)
+						.build()
+				},
+				Call::submit_amendment_attestation { check, signature } => {
+					Self::ensure_amendment_attestable(check, signature)?;
+
+					ValidTransaction::with_tag_prefix("BhdaoAmendmentAttestation")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(Self::get_pending_amendment(check.upload_id))
+						.longevity(ATTESTATION_RETRY_BLOCKS.into())
+						.propagate(true)
+						.build()
+				},
+				Call::submit_content_audit { check, signature } => {
+					Self::ensure_auditable(check, signature)?;
+
+					let now = <frame_system::Pallet<T>>::block_number();
+					ValidTransaction::with_tag_prefix("BhdaoContentAudit")
+						.priority(T::UnsignedPriority::get())
+						.and_provides((check.upload_id, Self::audit_epoch(now)))
+						.longevity(T::AuditPeriod::get().into())
+						.propagate(true)
+						.build()
+				},
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			match call {
+				Call::submit_content_attestation { check, signature } => Self::ensure_attestable(check, signature),
+				Call::submit_amendment_attestation { check, signature } => Self::ensure_amendment_attestable(check, signature),
+				Call::submit_content_audit { check, signature } => Self::ensure_auditable(check, signature),
+				_ => Err(InvalidTransaction::Call.into()),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		
+		#[pallet::call_index(0)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2).ref_time())]
 		pub fn join_dao(origin: OriginFor<T>, metadata: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(!Members::<T>::contains_key(&who.clone()), Error::<T>::MemberAlreadyExists);
-			let uid = Self::members_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			ensure!(!Members::<T>::contains_key(&who.clone()), Error::<T>::MemberAlreadyExists);
+			ensure!(!Banned::<T>::contains_key(&who), Error::<T>::AccountBanned);
+			let uid = Self::members_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let member = Member::<T> {
+				member_id: uid.clone(),
+				metadata: metadata,
+				vote_count: 0,
+				approved_contributions: 0,
+				role: Roles::Qualifier,
+				joined: now,
+				penalties: 0,
+			};
+
+			Members::<T>::insert(who.clone(),&member);
+			MembersCount::<T>::put(uid.clone());
+
+			Self::deposit_event(Event::MemberAdded { who, uid });
+
+			Ok(())
+
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,3).ref_time())]
+		pub fn upload_document(origin: OriginFor<T>, hash: Vec<u8>, categories: Vec<u32>, license: Option<u32>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let uid = Self::add_upload(&who, hash, categories, license, UploadStatus::QualificationVoteInProgress)?;
+
+			Self::start_qualification_vote(uid)
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,3).ref_time())]
+		pub fn cast_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: VoteChoice) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			// Check Vote Type
+			ensure!(vote_type == VoteType::Verification || vote_type == VoteType::Qualification || vote_type == VoteType::Proposal ||
+				vote_type == VoteType::Amendment || vote_type == VoteType::CollectionReview || vote_type == VoteType::Takedown, Error::<T>::WrongVoteType);
+			// Check the role
+			Self::ensure_eligible_to_vote(&member, vote_type)?;
+
+			// Own weight plus whatever members delegating to us haven't already voted themselves.
+			let delegated = Self::delegated_weight(&who, vote_type, voting_id);
+			let weight = Self::ballot_weight(&who, &member, vote_type)?.saturating_add(delegated);
+			Self::record_ballot_vote(vote_type, voting_id, &who, vote_cast, weight)?;
+
+			// A direct vote overrides our delegate's. Only ballots power can be delegated on are
+			// tracked.
+			if Self::is_delegable(vote_type) {
+				Self::withdraw_from_delegate(&who, vote_type, voting_id);
+				Self::record_open_ballot(&who, vote_type, voting_id, vote_cast, delegated)?;
+			}
+
+			Self::deposit_event(Event::VoteCast { vote_type: vote_type, uid: voting_id});
+
+			Ok(())
+		}
+
+		// A passed CollectionReview ballot starts an expert review for every upload in the
+		// collection, and a passed Takedown clears every amendment of the upload.
+		#[pallet::call_index(3)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,4).ref_time() + Pallet::<T>::settlement_weight().ref_time().saturating_mul(T::MaxSettlements::get().into()) + T::DbWeight::get().reads_writes(4,4).ref_time().saturating_mul(T::MaxCollectionSize::get().into()) + T::DbWeight::get().reads_writes(1,2).ref_time().saturating_mul(T::MaxAmendments::get().into()))]
+		pub fn finalize_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			// Check if the vote exists
+			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			vote.ensure_closed(now).map_err(Error::<T>::from)?;
+			// Check vote types
+			
+			let _ = OverriddenPower::<T>::clear_prefix((vote_type, voting_id), u32::MAX, None);
+
+			// Member actions also need a quorum of the Experts, and amendments content the gateway
+			// found intact.
+			let passed = vote.passed() && match vote_type {
+				VoteType::Proposal => Self::has_member_action_quorum(&vote),
+				VoteType::Amendment => Self::get_amendment_attestation(voting_id).map_or(false, |check| check.available && check.intact),
+				_ => true,
+			};
+			let passed = Self::conclude_ballot(vote_type, &mut vote, passed);
+			Votes::<T>::insert((vote_type.clone(),voting_id.clone()),&vote);
+			Self::deposit_event(Event::VoteEnded { vote_type: vote_type, uid: voting_id, status: vote.status, tally: vote.tally});
+			Self::settle_ballot(vote_type, voting_id, passed);
+			Self::on_finalized(vote_type, voting_id, passed)?;
+
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn apply_for_expanded_role(origin: OriginFor<T>,applied_role: Roles, domain: Option<u32>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check roles applied
+			ensure!(applied_role == Roles::Expert || applied_role == Roles::Verifier,Error::<T>::WrongRoleApplied);
+			// Check if member
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			let mut vote_type = VoteType::ExpertVerification;
+
+			if applied_role == Roles::Expert {
+				// Verifiers apply to become Experts in a domain, Experts to add another domain.
+				let domain = domain.ok_or(Error::<T>::DomainRequired)?;
+				ensure!(Domains::<T>::contains_key(domain), Error::<T>::UnknownDomain);
+				Self::ensure_meets_requirements(&who, Roles::Expert, Error::<T>::NotEligibleForExpertRole)?;
+				ensure!(!Self::is_domain_expert(domain, &who), Error::<T>::AlreadyDomainExpert);
+			}
+
+			if applied_role == Roles::Verifier {
+				Self::ensure_meets_requirements(&who, Roles::Verifier, Error::<T>::NotEligibleForVerifierRole)?;
+				vote_type = VoteType::CuratorVerification;
+			}
+			
+
+			let uid = Self::role_application_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let deposit = T::ApplicationDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			ApplicationDeposits::<T>::insert(uid, (who.clone(), deposit));
+
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let (start, end) = Self::ballot_window(vote_type);
+
+			let vote = Vote::<T>::new(start, end);
+
+
+			Votes::<T>::insert((vote_type,uid.clone()),vote);
+			Self::open_secret_ballot(vote_type, uid, end);
+			ExpertApplication::<T>::insert(uid.clone(),who.clone());
+			ApplicationCount::<T>::put(uid.clone());
+			LastApplication::<T>::insert(&who, now);
+
+			Self::deposit_event(Event::NewVote { vote_type, uid});
+
+			if let (Roles::Expert, Some(domain)) = (applied_role, domain) {
+				ApplicationDomains::<T>::insert(uid, domain);
+				Self::deposit_event(Event::ExpertiseClaimed { uid, who, domain });
+			}
+
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn cast_vote_for_expanded_role(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: VoteChoice) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			// Check Vote Type
+			ensure!(vote_type == VoteType::ExpertVerification || vote_type == VoteType::ExpertCouncilApproval ||
+				 vote_type == VoteType::CuratorVerification || vote_type == VoteType::CuratorCouncilApproval ||
+				 vote_type == VoteType::CollectorVerification || vote_type == VoteType::CollectorCouncilApproval, Error::<T>::WrongVoteType);
+
+			// Check the role
+			Self::ensure_eligible_to_vote(&member, vote_type)?;
+
+			let weight = Self::ballot_weight(&who, &member, vote_type)?;
+			Self::record_ballot_vote(vote_type, voting_id, &who, vote_cast, weight)?;
+
+			Self::deposit_event(Event::VoteCast { vote_type: vote_type, uid: voting_id});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time() + Pallet::<T>::settlement_weight().ref_time().saturating_mul(T::MaxSettlements::get().into()))]
+		pub fn finalize_vote_for_expanded_role(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Check if member
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			// Check if the vote exists
+			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
+			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+			let now = <frame_system::Pallet<T>>::block_number();
+			// Commit-reveal ballots close once the reveal window is over.
+			let closed = Self::get_secret_ballot((vote_type,voting_id)).map_or(vote.is_closed(now), |b| b.is_closed(now));
+			ensure!(closed,Error::<T>::VoteStillInProgress);
+			Self::close_secret_ballot(vote_type, voting_id);
+			// Collectors also need their KYC metadata attested by enough Experts.
+			let passed = vote.passed() &&
+				(vote_type != VoteType::CollectorCouncilApproval || Self::kyc_attestations(voting_id) >= T::MinKycAttestations::get());
+			let passed = Self::conclude_ballot(vote_type, &mut vote, passed);
+			Self::settle_ballot(vote_type, voting_id, passed);
+
+			Votes::<T>::insert((vote_type.clone(),voting_id.clone()),&vote);
+			Self::deposit_event(Event::VoteEnded { vote_type : vote_type, uid: voting_id, status: vote.status, tally: vote.tally});
+			Self::on_finalized(vote_type, voting_id, passed)?;
+
+			Ok(())
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn set_membership(origin: OriginFor<T>, new_member: T::AccountId, member_role: u8, metadata: Vec<u8>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!Members::<T>::contains_key(&new_member.clone()), Error::<T>::MemberAlreadyExists);
+
+			let role = match member_role {
+				1 => Roles::Qualifier,
+				2 => Roles::Contributor,
+				3 => Roles::Verifier,
+				4 => Roles::Expert,
+				5 => Roles::Collector,
+				_ => Roles::None,
+			};
+
+			let uid = Self::members_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let member = Member::<T> {
+				member_id: uid.clone(),
+				metadata: metadata,
+				vote_count: 0,
+				approved_contributions: 0,
+				role: role,
+				joined: now,
+				penalties: 0,
+			};
+
+			if role == Roles::Expert {
+				Self::add_expert(&new_member)?;
+			}
+			// Root readmits removed members.
+			Banned::<T>::remove(&new_member);
+			Members::<T>::insert(new_member.clone(),&member);
+			MembersCount::<T>::put(uid.clone());
+
+			Self::deposit_event(Event::MemberAdded { who: new_member, uid });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn raise_expert_objection(origin: OriginFor<T>, upload_id: u64, reason: Vec<u8>, category: ObjectionCategory, evidence: Vec<Vec<u8>>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let mut member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(member.role == Roles::Expert,Error::<T>::NotAnExpert);
+
+			let upload = Self::get_upload(upload_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
+
+			ensure!(upload.status == UploadStatus::UnderExpertReview,Error::<T>::NotUnderExpertReview);
+			ensure!(evidence.len() as u32 <= T::MaxObjectionEvidence::get(), Error::<T>::TooMuchEvidence);
+
+			let mut expert_review = Self::get_expert_review(upload_id.clone()).ok_or(Error::<T>::NotUnderExpertReview)?;
+			ensure!(expert_review.panel.contains(&who), Error::<T>::NotOnPanel);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(expert_review.is_open(now), Error::<T>::VotingWindowNotValid);
+
+			let deposit = T::ObjectionDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			// The other Experts decide on the objection in its own ballot.
+			let objection_id = Self::objection_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let (start, end) = Self::ballot_window(VoteType::Objection);
+
+			let vote = Vote::<T>::new(start, end);
+
+			Votes::<T>::insert((VoteType::Objection,objection_id),vote);
+			ObjectionUploads::<T>::insert(objection_id,upload_id);
+			ObjectionCount::<T>::put(objection_id);
+
+			let objection = Objection::<T> {
+				objector: who.clone(),
+				hash: reason,
+				uid: objection_id,
+				category,
+				evidence,
+				response: None,
+				deposit,
+				status: ObjectionStatus::Pending,
+			};
+
+			let mut object1 = expert_review.objections.clone();
+
+			match object1 {
+				Some(mut object1) => {
+					object1.push(objection);
+					expert_review.objections = Some(object1);
+				} ,
+				None => {
+					let mut obj1 = Vec::new();
+					obj1.push(objection);
+					expert_review.objections = Some(obj1);
+				},
+			}
+
+			if !expert_review.responded.contains(&who) {
+				expert_review.responded.push(who.clone());
+			}
+
+			ExpertReviews::<T>::insert(upload_id.clone(),expert_review);
+			Self::deposit_event(Event::ObjectionRaised {  uid: upload_id, who:  who});
+			Self::deposit_event(Event::NewVote { vote_type: VoteType::Objection, uid: objection_id});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time() + Pallet::<T>::settlement_weight().ref_time().saturating_mul(T::MaxSettlements::get().saturating_mul(T::ReviewPanelSize::get()).into()))]
+		pub fn finalize_expert_review(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			// Check if the vote exists
+			let expert_review = Self::get_expert_review(upload_id.clone()).ok_or(Error::<T>::NotUnderExpertReview)?;
+			let upload = Self::get_upload(upload_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::UnderExpertReview,Error::<T>::NotUnderExpertReview);
+			
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(expert_review.is_closed(now),Error::<T>::VoteStillInProgress);
+
+			// Nobody reviewed an upload that had no Experts to draw from. Draw a panel and start
+			// the review over, or leave the upload parked until there are Experts.
+			if expert_review.panel.is_empty() {
+				Self::start_expert_review(upload_id)?;
+				let redrawn = Self::get_expert_review(upload_id).map_or(false, |review| !review.panel.is_empty());
+				ensure!(redrawn, Error::<T>::NoEligibleExperts);
+				return Ok(())
+			}
+
+			let mut upheld = false;
+			if let Some(objections) = expert_review.objections.as_ref() {
+				for objection in objections.iter() {
+					let vote = Self::get_vote((VoteType::Objection,objection.uid)).ok_or(Error::<T>::VoteNotFound)?;
+					ensure!(vote.is_closed(now),Error::<T>::VoteStillInProgress);
+				}
+
+				for objection in objections.iter() {
+					let mut vote = Self::get_vote((VoteType::Objection,objection.uid)).ok_or(Error::<T>::VoteNotFound)?;
+					let passed = vote.passed();
+					let passed = Self::conclude_ballot(VoteType::Objection, &mut vote, passed);
+					Votes::<T>::insert((VoteType::Objection,objection.uid),&vote);
+					Self::settle_ballot(VoteType::Objection, objection.uid, passed);
+					Self::deposit_event(Event::VoteEnded { vote_type: VoteType::Objection, uid: objection.uid, status: vote.status, tally: vote.tally});
+					Self::on_finalized(VoteType::Objection, objection.uid, passed)?;
+					upheld = upheld || passed;
+				}
+			}
+
+			// Panelists who neither objected nor signed off lose reputation.
+			for panelist in expert_review.panel.iter().filter(|who| !expert_review.responded.contains(who)) {
+				if let Some(mut member) = Self::get_member(panelist.clone()) {
+					member.penalties = member.penalties.saturating_add(1);
+					Members::<T>::insert(panelist,&member);
+					Self::deposit_event(Event::PanelistPenalized { uid: upload_id, who: panelist.clone() });
+				}
+			}
+			Self::release_seats(upload_id, &expert_review.panel);
+			Self::deposit_event(Event::ExpertReviewEnded { uid: upload_id });
+
+			// Rejected only if an objection was upheld.
+			match upheld {
+				true => Self::reject_upload(upload_id)?,
+				false => Self::verify_upload(upload_id)?,
+			};
+
+			Ok(())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,4).ref_time())]
+		pub fn cast_conviction_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: VoteChoice, conviction: Conviction, balance: NativeBalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(Self::get_vote_weighting(vote_type) == VoteWeighting::Conviction, Error::<T>::NotConvictionWeighted);
+			Self::ensure_eligible_to_vote(&member, vote_type)?;
+
+			ensure!(!balance.is_zero(), Error::<T>::ZeroBalance);
+			ensure!(T::Currency::free_balance(&who) >= balance, Error::<T>::InsufficientBalance);
+
+			let weight = conviction.weight(balance.saturated_into::<u128>());
+			Self::record_ballot_vote(vote_type, voting_id, &who, vote_cast, weight)?;
+
+			// Lock the balance until the ballot ends plus the conviction's lock period.
+			let vote = Self::get_vote((vote_type,voting_id)).ok_or(Error::<T>::VoteNotFound)?;
+			let unlock_at = vote.end + (Self::ballot_timing(vote_type).window.saturating_mul(conviction.lock_windows())).into();
+			T::Currency::extend_lock(VOTING_LOCK_ID, &who, balance, WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT));
+			VotingLockExpiry::<T>::mutate(&who, |expiry| {
+				*expiry = Some(expiry.map_or(unlock_at, |e| e.max(unlock_at)));
+			});
+
+			Self::deposit_event(Event::ConvictionVoteCast { vote_type, uid: voting_id, who, weight });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2).ref_time())]
+		pub fn unlock_voting_balance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let expiry = Self::get_voting_lock_expiry(&who).ok_or(Error::<T>::NoVotingLock)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now > expiry, Error::<T>::VotingLockNotExpired);
+
+			T::Currency::remove_lock(VOTING_LOCK_ID, &who);
+			VotingLockExpiry::<T>::remove(&who);
+
+			Self::deposit_event(Event::VotingBalanceUnlocked { who });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_vote_weighting(origin: OriginFor<T>, vote_type: VoteType, weighting: VoteWeighting) -> DispatchResult {
+			ensure_root(origin)?;
+			// Council, objection, appeal and member-action ballots stay one expert one vote.
+			ensure!(
+				weighting == VoteWeighting::OneMemberOneVote ||
+					!(vote_type == VoteType::ExpertCouncilApproval || vote_type == VoteType::CuratorCouncilApproval ||
+						vote_type == VoteType::CollectorCouncilApproval || vote_type == VoteType::Objection || vote_type == VoteType::Appeal ||
+						vote_type == VoteType::Proposal),
+				Error::<T>::WeightingNotAllowed
+			);
+
+			// Conviction ballots are cast in the open and can't be committed.
+			ensure!(
+				weighting != VoteWeighting::Conviction || !Self::is_commit_reveal(vote_type),
+				Error::<T>::WeightingNotAllowed
+			);
+
+			VoteWeightings::<T>::insert(vote_type, weighting);
+			Self::deposit_event(Event::VoteWeightingSet { vote_type, weighting });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(8,6).ref_time().saturating_mul(T::MaxOpenBallots::get().into()))]
+		pub fn delegate(origin: OriginFor<T>, delegate: T::AccountId, vote_type: Option<VoteType>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			let delegate_member = Self::get_member(delegate.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+
+			let vote_types = match vote_type {
+				Some(vote_type) => {
+					ensure!(Self::is_delegable(vote_type), Error::<T>::WrongVoteType);
+					Self::ensure_eligible_to_vote(&member, vote_type)?;
+					vec![vote_type]
+				},
+				// Across the board: every delegable vote type we can vote in ourselves.
+				None => [VoteType::Qualification, VoteType::Verification]
+					.into_iter()
+					.filter(|vote_type| Self::ensure_eligible_to_vote(&member, *vote_type).is_ok())
+					.collect(),
+			};
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			for vote_type in vote_types {
+				ensure!(Self::ensure_eligible_to_vote(&delegate_member, vote_type).is_ok(), Error::<T>::DelegateNotEligible);
+				// Ballots we voted on without tracking them must be over before our power moves.
+				ensure!(now >= Self::get_untracked_ballots_end(&who, vote_type), Error::<T>::TooManyOpenBallots);
+
+				if Delegations::<T>::contains_key(&who, vote_type) {
+					Self::do_undelegate(&who, vote_type)?;
+				}
+				ensure!(Self::get_delegated_power(&delegate, vote_type).delegators < T::MaxDelegators::get(), Error::<T>::TooManyDelegators);
+
+				let power = DelegatedPower { delegators: 1, reputation: Self::reputation_weight(&who, &member) };
+				DelegatedPowers::<T>::mutate(&delegate, vote_type, |p| *p = p.saturating_add(power));
+				Delegators::<T>::mutate(&delegate, vote_type, |delegators| delegators.push(who.clone()));
+
+				let weight = Self::power_weight(vote_type, power);
+				let mut delegate_ballots = Self::prune_open_ballots(&delegate, vote_type);
+				for ballot in delegate_ballots.iter_mut() {
+					// Ballots our delegate voted on that we haven't voted on ourselves.
+					if !CheckVote::<T>::contains_key((who.clone(), vote_type, ballot.0)) {
+						Self::move_delegated_weight(vote_type, ballot, weight, true);
+					}
+				}
+				OpenBallots::<T>::insert(&delegate, vote_type, &delegate_ballots);
+				for (uid, _, _) in Self::prune_open_ballots(&who, vote_type) {
+					// Ballots we voted on ourselves that our delegate hasn't voted on yet.
+					if !delegate_ballots.iter().any(|(d_uid, _, _)| *d_uid == uid) {
+						OverriddenPower::<T>::mutate((vote_type, uid), &delegate, |p| *p = p.saturating_add(power));
+					}
+				}
+
+				Delegations::<T>::insert(&who, vote_type, Delegation::<T> { delegate: delegate.clone(), power });
+				Self::deposit_event(Event::Delegated { who: who.clone(), delegate: delegate.clone(), vote_type });
+			}
+
+			Ok(())
+		}
+
+		#[pallet::call_index(17)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(8,6).ref_time().saturating_mul(T::MaxOpenBallots::get().into()))]
+		pub fn undelegate(origin: OriginFor<T>, vote_type: Option<VoteType>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match vote_type {
+				Some(vote_type) => Self::do_undelegate(&who, vote_type)?,
+				None => {
+					let vote_types = [VoteType::Qualification, VoteType::Verification]
+						.into_iter()
+						.filter(|vote_type| Delegations::<T>::contains_key(&who, vote_type))
+						.collect::<Vec<_>>();
+					ensure!(!vote_types.is_empty(), Error::<T>::NotDelegating);
+					for vote_type in vote_types {
+						Self::do_undelegate(&who, vote_type)?;
+					}
+				},
+			}
+
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_commit_reveal(origin: OriginFor<T>, vote_type: VoteType, enabled: bool) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(vote_type == VoteType::ExpertVerification || vote_type == VoteType::ExpertCouncilApproval ||
+				 vote_type == VoteType::CuratorVerification || vote_type == VoteType::CuratorCouncilApproval ||
+				 vote_type == VoteType::CollectorVerification || vote_type == VoteType::CollectorCouncilApproval, Error::<T>::WrongVoteType);
+			ensure!(!enabled || Self::get_vote_weighting(vote_type) != VoteWeighting::Conviction, Error::<T>::WeightingNotAllowed);
+
+			// Only ballots opened from now on are affected.
+			CommitReveal::<T>::insert(vote_type, enabled);
+			Self::deposit_event(Event::CommitRevealSet { vote_type, enabled });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(19)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,2).ref_time())]
+		pub fn commit_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_eligible_to_vote(&member, vote_type)?;
+
+			let mut ballot = Self::get_secret_ballot((vote_type,voting_id)).ok_or(Error::<T>::NotCommitReveal)?;
+			let mut vote = Self::get_vote((vote_type,voting_id)).ok_or(Error::<T>::VoteNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			vote.ensure_open(now).map_err(Error::<T>::from)?;
+			ensure!(!VoteCommitments::<T>::contains_key((vote_type,voting_id), &who), Error::<T>::AlreadyVoted);
+
+			ballot.commits = ballot.commits.saturating_add(1);
+			SecretBallots::<T>::insert((vote_type,voting_id), ballot);
+			VoteCommitments::<T>::insert((vote_type,voting_id), &who, commitment);
+			Self::extend_if_late(vote_type, voting_id, &mut vote);
+			Votes::<T>::insert((vote_type,voting_id), vote);
+
+			Self::deposit_event(Event::VoteCommitted { vote_type, uid: voting_id, who });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,5).ref_time())]
+		pub fn reveal_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: VoteChoice, salt: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_eligible_to_vote(&member, vote_type)?;
+
+			let mut ballot = Self::get_secret_ballot((vote_type,voting_id)).ok_or(Error::<T>::NotCommitReveal)?;
+			let commitment = Self::get_vote_commitment((vote_type,voting_id), &who).ok_or(Error::<T>::NoCommitment)?;
+			ensure!(commitment == Self::vote_commitment(&who, vote_cast, &salt), Error::<T>::CommitmentMismatch);
+
+			// Only revealed ballots are tallied and count towards the member's record.
+			let weight = Self::ballot_weight(&who, &member, vote_type)?;
+			Self::record_revealed_vote(vote_type, voting_id, &who, vote_cast, weight)?;
+			VoteCommitments::<T>::remove((vote_type,voting_id), &who);
+
+			ballot.reveals = ballot.reveals.saturating_add(1);
+			SecretBallots::<T>::insert((vote_type,voting_id), ballot);
+
+			Self::deposit_event(Event::VoteRevealed { vote_type, uid: voting_id, who });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(21)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1).ref_time())]
+		pub fn respond_to_objection(origin: OriginFor<T>, objection_id: u64, response: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let upload_id = Self::get_objection_upload(objection_id).ok_or(Error::<T>::ObjectionNotFound)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.creator == who, Error::<T>::NotUploadCreator);
+
+			// The response has to be in while the Experts are still voting.
+			let vote = Self::get_vote((VoteType::Objection,objection_id)).ok_or(Error::<T>::VoteNotFound)?;
+			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(!vote.is_closed(now), Error::<T>::VotingWindowNotValid);
+
+			let mut expert_review = Self::get_expert_review(upload_id).ok_or(Error::<T>::NotUnderExpertReview)?;
+			let objection = expert_review.objections.iter_mut().flatten()
+				.find(|objection| objection.uid == objection_id)
+				.ok_or(Error::<T>::ObjectionNotFound)?;
+			ensure!(objection.response.is_none(), Error::<T>::AlreadyResponded);
+			objection.response = Some(response);
+
+			ExpertReviews::<T>::insert(upload_id,expert_review);
+			Self::deposit_event(Event::ObjectionResponded { uid: upload_id, objection_id });
+
+			Ok(())
+		}
+
+		/// Vote to uphold (`true`) or dismiss (`false`) an objection.
+		#[pallet::call_index(22)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,3).ref_time())]
+		pub fn adjudicate_objection(origin: OriginFor<T>, objection_id: u64, vote_cast: VoteChoice) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_eligible_to_vote(&member, VoteType::Objection)?;
+
+			let upload_id = Self::get_objection_upload(objection_id).ok_or(Error::<T>::ObjectionNotFound)?;
+			let expert_review = Self::get_expert_review(upload_id).ok_or(Error::<T>::NotUnderExpertReview)?;
+			let objection = expert_review.objections.iter().flatten()
+				.find(|objection| objection.uid == objection_id)
+				.ok_or(Error::<T>::ObjectionNotFound)?;
+			ensure!(objection.objector != who, Error::<T>::ObjectorCannotAdjudicate);
+
+			let weight = Self::ballot_weight(&who, &member, VoteType::Objection)?;
+			Self::record_ballot_vote(VoteType::Objection, objection_id, &who, vote_cast, weight)?;
+
+			Self::deposit_event(Event::VoteCast { vote_type: VoteType::Objection, uid: objection_id});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(23)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(8,6).ref_time())]
+		pub fn file_appeal(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.creator == who, Error::<T>::NotUploadCreator);
+			ensure!(upload.status == UploadStatus::Rejected, Error::<T>::NotRejected);
+
+			let mut rejection = Self::get_rejection(upload_id).ok_or(Error::<T>::NotRejected)?;
+			ensure!(!rejection.appealed, Error::<T>::AlreadyAppealed);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now <= rejection.at + T::AppealPeriod::get().into(), Error::<T>::AppealPeriodOver);
+
+			let appeal_id = Self::appeal_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let candidates = Self::appeal_candidates(upload_id, &upload.creator);
+			let panel = Self::draw_panel(&(b"bhdao/appeal", appeal_id).encode(), candidates, T::AppealPanelSize::get());
+			ensure!(!panel.is_empty(), Error::<T>::NoEligibleExperts);
+
+			let deposit = T::AppealDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			let (start, end) = Self::ballot_window(VoteType::Appeal);
+
+			let vote = Vote::<T>::new(start, end);
+
+			let appeal = Appeal::<T> {
+				upload_id,
+				appellant: who,
+				stage: rejection.stage,
+				deposit,
+				panel: panel.clone(),
+				filed: now,
+				status: VoteStatus::InProgress,
+			};
+
+			Votes::<T>::insert((VoteType::Appeal,appeal_id),vote);
+			Appeals::<T>::insert(appeal_id,appeal);
+			Self::take_seats(upload_id, &panel);
+			UploadAppeals::<T>::append(upload_id,appeal_id);
+			AppealCount::<T>::put(appeal_id);
+			rejection.appealed = true;
+			Rejections::<T>::insert(upload_id,rejection);
+
+			Self::deposit_event(Event::AppealFiled { uid: upload_id, appeal_id, panel });
+			Self::deposit_event(Event::NewVote { vote_type: VoteType::Appeal, uid: appeal_id});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(24)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,3).ref_time())]
+		pub fn cast_appeal_vote(origin: OriginFor<T>, appeal_id: u64, vote_cast: VoteChoice) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			// Check if member
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_eligible_to_vote(&member, VoteType::Appeal)?;
+
+			let appeal = Self::get_appeal(appeal_id).ok_or(Error::<T>::AppealNotFound)?;
+			ensure!(appeal.panel.contains(&who), Error::<T>::NotOnPanel);
+
+			let weight = Self::ballot_weight(&who, &member, VoteType::Appeal)?;
+			Self::record_ballot_vote(VoteType::Appeal, appeal_id, &who, vote_cast, weight)?;
+
+			Self::deposit_event(Event::VoteCast { vote_type: VoteType::Appeal, uid: appeal_id});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(25)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,6).ref_time() + Pallet::<T>::settlement_weight().ref_time().saturating_mul(T::MaxSettlements::get().into()))]
+		pub fn finalize_appeal(origin: OriginFor<T>, appeal_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(Appeals::<T>::contains_key(appeal_id), Error::<T>::AppealNotFound);
+			let mut vote = Self::get_vote((VoteType::Appeal,appeal_id)).ok_or(Error::<T>::VoteNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			vote.ensure_closed(now).map_err(Error::<T>::from)?;
+
+			let passed = vote.passed();
+			let passed = Self::conclude_ballot(VoteType::Appeal, &mut vote, passed);
+			Votes::<T>::insert((VoteType::Appeal,appeal_id),&vote);
+			Self::settle_ballot(VoteType::Appeal, appeal_id, passed);
+			Self::deposit_event(Event::VoteEnded { vote_type: VoteType::Appeal, uid: appeal_id, status: vote.status, tally: vote.tally});
+			Self::on_finalized(VoteType::Appeal, appeal_id, passed)?;
+
+			Ok(())
+		}
+
+		/// Sign off an expert review without objecting.
+		#[pallet::call_index(26)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn sign_off_review(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::UnderExpertReview,Error::<T>::NotUnderExpertReview);
+
+			let mut expert_review = Self::get_expert_review(upload_id).ok_or(Error::<T>::NotUnderExpertReview)?;
+			ensure!(expert_review.panel.contains(&who), Error::<T>::NotOnPanel);
+			ensure!(!expert_review.responded.contains(&who), Error::<T>::AlreadyRespondedToReview);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(expert_review.is_open(now), Error::<T>::VotingWindowNotValid);
+
+			expert_review.responded.push(who.clone());
+			ExpertReviews::<T>::insert(upload_id,expert_review);
+			Self::deposit_event(Event::ReviewSignedOff { uid: upload_id, who });
+
+			Ok(())
+		}
+
+		/// Keep the caller off review and appeal panels for `contributor`'s uploads.
+		#[pallet::call_index(27)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn declare_conflict(origin: OriginFor<T>, contributor: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+
+			Conflicts::<T>::insert(&who, &contributor, true);
+			Self::deposit_event(Event::ConflictDeclared { who, contributor });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(28)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn withdraw_conflict(origin: OriginFor<T>, contributor: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::has_conflict(&who, &contributor), Error::<T>::NoConflict);
+
+			Conflicts::<T>::remove(&who, &contributor);
+			Self::deposit_event(Event::ConflictWithdrawn { who, contributor });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(29)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2).ref_time())]
+		pub fn add_domain(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let domain = Self::domain_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Domains::<T>::insert(domain, name.clone());
+			DomainCount::<T>::put(domain);
+			Self::deposit_event(Event::DomainAdded { domain, name });
+
+			Ok(())
+		}
+
+		/// Put a demotion, suspension or removal of `target` to a vote of the Experts, against a
+		/// deposit that is slashed if the ballot fails.
+		#[pallet::call_index(30)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,5).ref_time())]
+		pub fn propose_member_action(origin: OriginFor<T>, target: T::AccountId, action: MemberAction<T>, reason: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			let target_member = Self::get_member(target.clone()).ok_or(Error::<T>::NotAMember)?;
+			if let MemberAction::Demote(role) = action {
+				ensure!(Self::is_demotion(target_member.role, role), Error::<T>::InvalidDemotion);
+			}
+
+			let uid = Self::proposal_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let deposit = T::MemberActionDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			MemberActionDeposits::<T>::insert(uid, (who.clone(), deposit));
+
+			let proposal = MemberProposal::<T> {
+				proposer: who,
+				target: target.clone(),
+				action: action.clone(),
+				reason: reason.clone(),
+			};
+			MemberProposals::<T>::insert(uid,proposal);
+			ProposalCount::<T>::put(uid);
+
+			let (start, end) = Self::ballot_window(VoteType::Proposal);
+			let vote = Vote::<T>::new(start, end);
+			let vote_type = VoteType::Proposal;
+			Votes::<T>::insert((vote_type,uid),vote);
+
+			Self::deposit_event(Event::MemberActionProposed { uid, target, action, reason });
+			Self::deposit_event(Event::NewVote { vote_type, uid });
+
+			Ok(())
+		}
+
+		/// Leave the DAO. Suspended members can't resign to rejoin with a clean slate.
+		#[pallet::call_index(31)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(12,12).ref_time().saturating_mul(T::MaxDelegators::get().into()))]
+		pub fn resign(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+
+			Self::remove_member(&who);
+			Self::deposit_event(Event::MemberResigned { who });
+
+			Ok(())
+		}
+
+		/// Emergency suspension of a member without a vote.
+		#[pallet::call_index(32)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn suspend_member(origin: OriginFor<T>, who: T::AccountId, reason: Vec<u8>, until: Option<T::BlockNumber>) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+
+			Self::suspend(who, reason, until);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(33)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn lift_suspension(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(Suspensions::<T>::contains_key(&who), Error::<T>::NotSuspended);
+
+			Suspensions::<T>::remove(&who);
+			Self::deposit_event(Event::SuspensionLifted { who });
+
+			Ok(())
+		}
+
+		/// Apply for the Collector role with KYC metadata (e.g. a hash of the institution's documents).
+		#[pallet::call_index(34)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,4).ref_time())]
+		pub fn apply_for_collector_role(origin: OriginFor<T>, kyc_metadata: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			Self::ensure_meets_requirements(&who, Roles::Collector, Error::<T>::NotEligibleForCollectorRole)?;
+
+			let uid = Self::role_application_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let deposit = T::ApplicationDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			ApplicationDeposits::<T>::insert(uid, (who.clone(), deposit));
+			let now = <frame_system::Pallet<T>>::block_number();
+			let (start, end) = Self::ballot_window(VoteType::CollectorVerification);
+
+			let vote = Vote::<T>::new(start, end);
+
+			let vote_type = VoteType::CollectorVerification;
+
+			Votes::<T>::insert((vote_type,uid),vote);
+			Self::open_secret_ballot(vote_type, uid, end);
+			ExpertApplication::<T>::insert(uid,who.clone());
+			CollectorApplications::<T>::insert(uid,kyc_metadata);
+			ApplicationCount::<T>::put(uid);
+			LastApplication::<T>::insert(&who, now);
+
+			Self::deposit_event(Event::CollectorApplied { uid, who });
+			Self::deposit_event(Event::NewVote { vote_type, uid });
+
+			Ok(())
+		}
+
+		/// Attest, as an Expert, that the KYC metadata of a Collector application checks out.
+		#[pallet::call_index(35)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1).ref_time())]
+		pub fn attest_collector_kyc(origin: OriginFor<T>, application_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(member.role == Roles::Expert, Error::<T>::NotAnExpert);
+			ensure!(CollectorApplications::<T>::contains_key(application_id), Error::<T>::NotACollectorApplication);
+			let applicant = Self::get_role_application(application_id).ok_or(Error::<T>::ApplicationNotFound)?;
+			ensure!(applicant != who, Error::<T>::CannotAttestOwnApplication);
+			ensure!(!Self::has_attested_kyc(application_id, &who), Error::<T>::AlreadyAttested);
+
+			KycAttestations::<T>::insert(application_id, &who, true);
+			Self::deposit_event(Event::KycAttested { uid: application_id, who });
+
+			Ok(())
+		}
+
+		/// Reserve `amount` behind an upload in review. It goes to the contributor if the upload
+		/// is Verified and back to the Collector if it is rejected.
+		#[pallet::call_index(36)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2).ref_time())]
+		pub fn sponsor_upload(origin: OriginFor<T>, upload_id: u64, amount: NativeBalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(member.role == Roles::Collector, Error::<T>::NotACollector);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroBalance);
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(
+				upload.status != UploadStatus::Verified && upload.status != UploadStatus::Rejected && upload.status != UploadStatus::Withdrawn,
+				Error::<T>::UploadNotInReview
+			);
+
+			T::Currency::reserve(&who, amount).map_err(|_| Error::<T>::InsufficientBalance)?;
+			Sponsorships::<T>::mutate(upload_id, &who, |sponsored| *sponsored = sponsored.saturating_add(amount));
+			Self::deposit_event(Event::UploadSponsored { uid: upload_id, who, amount });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(37)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_role_requirement(origin: OriginFor<T>, role: Roles, requirement: RoleRequirement) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(role != Roles::None && role != Roles::Qualifier, Error::<T>::WrongRoleApplied);
+
+			RoleRequirements::<T>::insert(role, requirement.clone());
+			Self::deposit_event(Event::RoleRequirementSet { role, requirement });
+
+			Ok(())
+		}
+
+		/// Set the window, opening delay and late-vote extensions of `vote_type` ballots.
+		/// Only ballots opened from now on are affected.
+		#[pallet::call_index(38)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_ballot_timing(origin: OriginFor<T>, vote_type: VoteType, timing: BallotTiming) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(timing.window > 0, Error::<T>::InvalidTiming);
+
+			BallotTimings::<T>::insert(vote_type, timing.clone());
+			Self::deposit_event(Event::BallotTimingSet { vote_type, timing });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(39)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_expert_review_window(origin: OriginFor<T>, window: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(window > 0, Error::<T>::InvalidTiming);
+
+			ExpertReviewWindow::<T>::put(window);
+			Self::deposit_event(Event::ExpertReviewWindowSet { window });
+
+			Ok(())
+		}
+
+		/// Publish the hash of the caller's rationale for their vote on a ballot.
+		#[pallet::call_index(40)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1).ref_time())]
+		pub fn record_rationale(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, rationale: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				CheckVote::<T>::contains_key((who.clone(),vote_type,voting_id)) ||
+					VoteCommitments::<T>::contains_key((vote_type,voting_id), &who),
+				Error::<T>::NotVoted
+			);
+			// Rationales explain a vote while the ballot is still being decided, not afterwards.
+			let vote = Self::get_vote((vote_type,voting_id)).ok_or(Error::<T>::VoteNotFound)?;
+			vote.ensure_open(<frame_system::Pallet<T>>::block_number()).map_err(Error::<T>::from)?;
+
+			Rationales::<T>::insert((vote_type,voting_id), &who, rationale);
+			Self::deposit_event(Event::RationaleRecorded { vote_type, uid: voting_id, who, rationale });
+
+			Ok(())
+		}
+
+		/// Propose `hash` as the corrected content of a Verified upload the caller created or
+		/// co-contributed. Verifiers decide it on a single `VoteType::Amendment` ballot instead of
+		/// the full review track. The proposer reserves `AmendmentDeposit`, which is slashed if the
+		/// amendment fails.
+		#[pallet::call_index(41)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,7).ref_time())]
+		pub fn submit_amendment(origin: OriginFor<T>, upload_id: u64, hash: Vec<u8>, reason: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(member.role == Roles::Contributor || member.role == Roles::Collector, Error::<T>::NotEligibleToContribute);
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::Verified, Error::<T>::NotVerified);
+			ensure!(
+				upload.creator == who || Self::get_co_contributors(upload_id).iter().any(|(account,_)| *account == who),
+				Error::<T>::NotUploadContributor
+			);
+			ensure!(!PendingAmendments::<T>::contains_key(upload_id), Error::<T>::AmendmentPending);
+			ensure!(!PendingTakedowns::<T>::contains_key(upload_id), Error::<T>::TakedownPending);
+			ensure!(
+				(UploadAmendments::<T>::decode_len(upload_id).unwrap_or_default() as u32) < T::MaxAmendments::get(),
+				Error::<T>::TooManyAmendments
+			);
+
+			let uid = Self::amendment_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let deposit = T::AmendmentDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			AmendmentDeposits::<T>::insert(uid, (who.clone(), deposit));
+			let amendment = Amendment::<T> {
+				upload_id,
+				proposer: who.clone(),
+				hash,
+				reason,
+				status: VoteStatus::InProgress,
+			};
+			Amendments::<T>::insert(uid, amendment);
+			AmendmentCount::<T>::put(uid);
+			UploadAmendments::<T>::append(upload_id, uid);
+			PendingAmendments::<T>::insert(upload_id, uid);
+
+			let vote_type = VoteType::Amendment;
+			let (start, end) = Self::ballot_window(vote_type);
+			Votes::<T>::insert((vote_type,uid), Vote::<T>::new(start, end));
+
+			Self::deposit_event(Event::AmendmentSubmitted { uid, upload_id, who });
+			Self::deposit_event(Event::NewVote { vote_type, uid });
+
+			Ok(())
+		}
+
+		/// Start a collection curated by the caller, with a matching pallet_nft collection.
+		#[pallet::call_index(42)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3).ref_time())]
+		pub fn create_collection(origin: OriginFor<T>, description: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
+			ensure!(member.role == Roles::Contributor || member.role == Roles::Collector, Error::<T>::NotEligibleToContribute);
+
+			let uid = Self::collection_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			pallet_nft::Pallet::<T>::_create_collection(uid, who.clone())?;
+			let collection = Collection::<T> {
+				curator: who.clone(),
+				description,
+				uploads: Vec::new(),
+				status: CollectionStatus::Open,
+			};
+			Collections::<T>::insert(uid, collection);
+			CollectionCount::<T>::put(uid);
+
+			Self::deposit_event(Event::CollectionCreated { uid, curator: who });
+
+			Ok(())
+		}
+
+		/// Add an existing upload to an open collection: one of the curator's own, or a Verified
+		/// one whose creator consented with `consent_to_collection`. Rejected, withdrawn and
+		/// redacted uploads can't be added.
+		#[pallet::call_index(43)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,4).ref_time())]
+		pub fn add_to_collection(origin: OriginFor<T>, collection_id: u64, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let mut collection = Self::curated_collection(&who, collection_id)?;
+			ensure!(collection.status == CollectionStatus::Open, Error::<T>::CollectionUnderReview);
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(
+				!matches!(upload.status, UploadStatus::Rejected | UploadStatus::Redacted | UploadStatus::Withdrawn),
+				Error::<T>::UploadClosed
+			);
+			let consented = upload.status == UploadStatus::Verified && CollectionConsents::<T>::take(upload_id, collection_id).is_some();
+			ensure!(upload.creator == who || consented, Error::<T>::CannotCollectUpload);
+
+			Self::collect(&mut collection, collection_id, upload_id)?;
+			if let Some(token) = Self::get_upload_token(upload_id) {
+				pallet_nft::Pallet::<T>::_add_to_collection(collection_id, token)?;
+			}
+			Collections::<T>::insert(collection_id, collection);
+
+			Ok(())
+		}
+
+		/// Upload a document into a collection. It waits there to be reviewed with the rest of
+		/// the collection instead of starting its own review.
+		#[pallet::call_index(44)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,4).ref_time())]
+		pub fn upload_to_collection(origin: OriginFor<T>, collection_id: u64, hash: Vec<u8>, categories: Vec<u32>, license: Option<u32>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut collection = Self::curated_collection(&who, collection_id)?;
+			ensure!(collection.status == CollectionStatus::Open, Error::<T>::CollectionUnderReview);
+			ensure!((collection.uploads.len() as u32) < T::MaxCollectionSize::get(), Error::<T>::CollectionFull);
+
+			let upload_id = Self::add_upload(&who, hash, categories, license, UploadStatus::InCollection)?;
+			Self::collect(&mut collection, collection_id, upload_id)?;
+			Collections::<T>::insert(collection_id, collection);
+
+			Ok(())
+		}
+
+		/// Reorder a collection. `order` must list each of its uploads exactly once.
+		#[pallet::call_index(45)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn reorder_collection(origin: OriginFor<T>, collection_id: u64, order: Vec<u64>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let mut collection = Self::curated_collection(&who, collection_id)?;
+
+			let mut current = collection.uploads.clone();
+			let mut proposed = order.clone();
+			current.sort();
+			proposed.sort();
+			ensure!(current == proposed, Error::<T>::InvalidOrder);
+
+			collection.uploads = order;
+			Collections::<T>::insert(collection_id, collection);
+			Self::deposit_event(Event::CollectionReordered { uid: collection_id });
+
+			Ok(())
+		}
+
+		/// Let the curator of a collection add the caller's Verified upload to it.
+		#[pallet::call_index(58)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn consent_to_collection(origin: OriginFor<T>, upload_id: u64, collection_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.creator == who, Error::<T>::NotUploadCreator);
+			ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionNotFound);
+
+			CollectionConsents::<T>::insert(upload_id, collection_id, ());
+			Self::deposit_event(Event::CollectionConsentGiven { uid: collection_id, upload_id });
+
+			Ok(())
+		}
+
+		/// Put every `InCollection` upload of a collection to a single `VoteType::CollectionReview`
+		/// ballot of Verifiers. If it passes each of them goes on to its own expert review,
+		/// otherwise they are all rejected.
+		#[pallet::call_index(46)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2).ref_time())]
+		pub fn submit_collection(origin: OriginFor<T>, collection_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_suspended(&who)?;
+			let mut collection = Self::curated_collection(&who, collection_id)?;
+			ensure!(collection.status == CollectionStatus::Open, Error::<T>::CollectionUnderReview);
+			ensure!(
+				collection.uploads.iter().any(|uid| Self::get_upload(uid).map_or(false, |upload| upload.status == UploadStatus::InCollection)),
+				Error::<T>::NothingToReview
+			);
+
+			collection.status = CollectionStatus::UnderReview;
+			Collections::<T>::insert(collection_id, collection);
+
+			let vote_type = VoteType::CollectionReview;
+			let (start, end) = Self::ballot_window(vote_type);
+			Votes::<T>::insert((vote_type,collection_id), Vote::<T>::new(start, end));
+
+			Self::deposit_event(Event::CollectionSubmitted { uid: collection_id });
+			Self::deposit_event(Event::NewVote { vote_type, uid: collection_id });
+
+			Ok(())
+		}
+
+		/// Upload a document made together with co-contributors, each given a share in basis
+		/// points of the contributor tokens and royalties. The creator keeps what is left.
+		/// Qualification opens once every co-contributor has accepted.
+		#[pallet::call_index(47)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,4).ref_time())]
+		pub fn upload_with_co_contributors(origin: OriginFor<T>, hash: Vec<u8>, categories: Vec<u32>, license: Option<u32>, co_contributors: Vec<(T::AccountId,u16)>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(co_contributors.len() as u32 <= T::MaxCoContributors::get(), Error::<T>::TooManyCoContributors);
+
+			let mut accounts = co_contributors.iter().map(|(account,_)| account.clone()).collect::<Vec<_>>();
+			accounts.sort();
+			accounts.dedup();
+			let total = co_contributors.iter().fold(0u32, |total, (_,share)| total.saturating_add(*share as u32));
+			ensure!(
+				!co_contributors.is_empty()
+					&& accounts.len() == co_contributors.len()
+					&& !accounts.contains(&who)
+					&& co_contributors.iter().all(|(_,share)| *share > 0)
+					&& total < 10_000,
+				Error::<T>::InvalidCoContributors
+			);
+
+			let uid = Self::add_upload(&who, hash, categories, license, UploadStatus::AwaitingCoContributors)?;
+			CoContributors::<T>::insert(uid, co_contributors);
+			PendingCoContributors::<T>::insert(uid, accounts);
+
+			Ok(())
+		}
+
+		/// Accept being named as a co-contributor of an upload.
+		#[pallet::call_index(48)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,3).ref_time())]
+		pub fn accept_co_contribution(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::AwaitingCoContributors, Error::<T>::NotAwaitingCoContributors);
+
+			let mut pending = Self::get_pending_co_contributors(upload_id);
+			let position = pending.iter().position(|account| account == &who).ok_or(Error::<T>::NotACoContributor)?;
+			pending.remove(position);
+			Self::deposit_event(Event::CoContributionAccepted { uid: upload_id, who });
+
+			if !pending.is_empty() {
+				PendingCoContributors::<T>::insert(upload_id, pending);
+				return Ok(());
+			}
+
+			PendingCoContributors::<T>::remove(upload_id);
+			upload.status = UploadStatus::QualificationVoteInProgress;
+			Uploads::<T>::insert(upload_id, &upload);
+
+			Self::start_qualification_vote(upload_id)
+		}
+
+		/// Decline being named as a co-contributor of an upload, which withdraws the upload.
+		#[pallet::call_index(59)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,4).ref_time())]
+		pub fn decline_co_contribution(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::AwaitingCoContributors, Error::<T>::NotAwaitingCoContributors);
+			ensure!(Self::get_pending_co_contributors(upload_id).contains(&who), Error::<T>::NotACoContributor);
+
+			Self::deposit_event(Event::CoContributionDeclined { uid: upload_id, who });
+			Self::withdraw_upload(upload_id)
+		}
+
+		/// Withdraw one of the caller's uploads that is still waiting for its co-contributors.
+		#[pallet::call_index(60)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,4).ref_time())]
+		pub fn cancel_co_contributed_upload(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.creator == who, Error::<T>::NotUploadCreator);
+			ensure!(upload.status == UploadStatus::AwaitingCoContributors, Error::<T>::NotAwaitingCoContributors);
+
+			Self::withdraw_upload(upload_id)
+		}
+
+		/// Flag an upload still in review as containing personal information of the named
+		/// subjects, living people or their estates. It cannot be Verified until each of them
+		/// consents. Subjects can be added later but never removed.
+		#[pallet::call_index(49)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn mark_sensitive(origin: OriginFor<T>, upload_id: u64, subjects: Vec<T::AccountId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.creator == who, Error::<T>::NotUploadCreator);
+			ensure!(
+				!matches!(upload.status, UploadStatus::Verified | UploadStatus::Rejected | UploadStatus::Redacted | UploadStatus::Withdrawn),
+				Error::<T>::UploadClosed
+			);
+
+			let mut named = Self::get_subjects(upload_id).unwrap_or_default();
+			for subject in subjects {
+				if !named.contains(&subject) {
+					named.push(subject);
+				}
+			}
+			ensure!(named.len() as u32 <= T::MaxSubjects::get(), Error::<T>::TooManySubjects);
+
+			SensitiveUploads::<T>::insert(upload_id, &named);
+			Self::deposit_event(Event::UploadMarkedSensitive { uid: upload_id, subjects: named });
+
+			Ok(())
+		}
+
+		/// Attest, as a named subject of a sensitive upload, to consenting to its publication.
+		/// `consent` is the hash of the signed consent document.
+		#[pallet::call_index(50)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn attest_consent(origin: OriginFor<T>, upload_id: u64, consent: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let subjects = Self::get_subjects(upload_id).ok_or(Error::<T>::NotASubject)?;
+			ensure!(subjects.contains(&who), Error::<T>::NotASubject);
+
+			Consents::<T>::insert(upload_id, &who, consent);
+			Self::deposit_event(Event::ConsentAttested { uid: upload_id, who });
+
+			Ok(())
+		}
+
+		/// Ask the Experts to take a Verified upload down, against a deposit that is slashed if
+		/// they veto it. Named subjects of the upload can request it without being members. After
+		/// a rejected takedown the upload can't be put to another one for `TakedownCooldown` blocks.
+		#[pallet::call_index(51)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,6).ref_time())]
+		pub fn request_takedown(origin: OriginFor<T>, upload_id: u64, reason: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if !Self::get_subjects(upload_id).map_or(false, |subjects| subjects.contains(&who)) {
+				Self::ensure_not_suspended(&who)?;
+				ensure!(Members::<T>::contains_key(&who), Error::<T>::NotAMember);
+			}
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::Verified, Error::<T>::NotVerified);
+			ensure!(!PendingTakedowns::<T>::contains_key(upload_id), Error::<T>::TakedownPending);
+			ensure!(!PendingAmendments::<T>::contains_key(upload_id), Error::<T>::AmendmentPending);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(Self::get_takedown_cooldown(upload_id).map_or(true, |until| now >= until), Error::<T>::TakedownCoolingDown);
+
+			let uid = Self::takedown_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let deposit = T::TakedownDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			TakedownDeposits::<T>::insert(uid, (who.clone(), deposit));
+
+			// No trading while the takedown is decided.
+			let mut froze_token = false;
+			if let Some(token) = Self::get_upload_token(upload_id) {
+				if !pallet_nft::Pallet::<T>::is_frozen(token) {
+					pallet_nft::Pallet::<T>::_freeze_token(token)?;
+					froze_token = true;
+				}
+			}
+
+			let takedown = Takedown::<T> {
+				upload_id,
+				requester: who.clone(),
+				reason,
+				status: VoteStatus::InProgress,
+				froze_token,
+			};
+			Takedowns::<T>::insert(uid, takedown);
+			TakedownCount::<T>::put(uid);
+			PendingTakedowns::<T>::insert(upload_id, uid);
+
+			let vote_type = VoteType::Takedown;
+			let (start, end) = Self::ballot_window(vote_type);
+			Votes::<T>::insert((vote_type,uid), Vote::<T>::new(start, end));
+
+			Self::deposit_event(Event::TakedownRequested { uid, upload_id, who });
+			Self::deposit_event(Event::NewVote { vote_type, uid });
+
+			Ok(())
+		}
+
+		/// Add a license to the registry contributors choose from.
+		#[pallet::call_index(52)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn register_license(origin: OriginFor<T>, license_id: u32, license: License<T::Hash>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!Licenses::<T>::contains_key(license_id), Error::<T>::LicenseAlreadyRegistered);
+
+			Licenses::<T>::insert(license_id, license);
+			Self::deposit_event(Event::LicenseRegistered { license_id });
+
+			Ok(())
+		}
+
+		/// Stop a license from being chosen for new uploads or license changes.
+		#[pallet::call_index(53)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn retire_license(origin: OriginFor<T>, license_id: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_license_available(license_id)?;
+
+			RetiredLicenses::<T>::insert(license_id, true);
+			Self::deposit_event(Event::LicenseRetired { license_id });
+
+			Ok(())
+		}
+
+		/// Ask the holders of a Verified upload's token to move it to another license. This
+		/// opens a pallet_nft `ProposalKind::Licensing` proposal on the token, whose terms are the
+		/// hash of the license itself.
+		#[pallet::call_index(54)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,4).ref_time())]
+		pub fn propose_license_change(origin: OriginFor<T>, upload_id: u64, license: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let token = Self::get_upload_token(upload_id).ok_or(Error::<T>::NotVerified)?;
+			Self::ensure_not_redacted(upload_id)?;
+			Self::ensure_license_available(license)?;
+			let terms = Self::get_license(license).ok_or(Error::<T>::UnknownLicense)?;
+
+			let proposal_id = pallet_nft::Pallet::<T>::_propose(who, token, pallet_nft::ProposalKind::Licensing, T::Hashing::hash_of(&terms))?;
+			LicenseChanges::<T>::insert(proposal_id, (upload_id, license));
+
+			Self::deposit_event(Event::LicenseChangeProposed { uid: upload_id, proposal_id, license });
+
+			Ok(())
+		}
+
+		/// Close a license change proposal once its voting has ended, and move the upload and its
+		/// token to the new license if holders of more than half the token supply agreed. The
+		/// change is rejected if the license was retired or the upload redacted in the meantime.
+		#[pallet::call_index(55)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(7,4).ref_time())]
+		pub fn apply_license_change(origin: OriginFor<T>, proposal_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			let (upload_id, license) = Self::get_license_change(proposal_id).ok_or(Error::<T>::LicenseChangeNotFound)?;
+			let proposal = pallet_nft::Pallet::<T>::get_holder_proposal(proposal_id).ok_or(Error::<T>::LicenseChangeNotFound)?;
+
+			// Holders may already have closed it through pallet_nft.
+			let status = match proposal.status {
+				pallet_nft::ProposalStatus::Open => pallet_nft::Pallet::<T>::_close_proposal(proposal_id)?,
+				status => status,
+			};
+			LicenseChanges::<T>::remove(proposal_id);
+
+			let applicable = Self::ensure_license_available(license).is_ok() && Self::ensure_not_redacted(upload_id).is_ok();
+			match status {
+				pallet_nft::ProposalStatus::Passed if applicable => {
+					UploadLicenses::<T>::insert(upload_id, license);
+					pallet_nft::Pallet::<T>::_set_token_license(proposal.token, license)?;
+					Self::deposit_event(Event::UploadLicenseChanged { uid: upload_id, license });
+				},
+				_ => Self::deposit_event(Event::LicenseChangeRejected { uid: upload_id, proposal_id }),
+			};
+
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_content_gateway(origin: OriginFor<T>, gateway: Vec<u8>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ContentGateway::<T>::put(gateway.clone());
+			Self::deposit_event(Event::ContentGatewaySet { gateway });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(56)]
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		pub fn set_attesters(origin: OriginFor<T>, attesters: Vec<T::AccountId>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Attesters::<T>::put(attesters.clone());
+			Self::deposit_event(Event::AttestersSet { attesters });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn submit_content_attestation(
+			origin: OriginFor<T>,
+			check: ContentCheck<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let ContentCheck { upload_id, available, intact, .. } = check;
+
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::QualificationVoteInProgress, Error::<T>::NotInQualification);
+			ensure!(!ContentAttestations::<T>::contains_key(upload_id), Error::<T>::ContentAlreadyAttested);
+
+			let attestation = ContentAttestation::<T> {
+				available,
+				intact,
+				checked_at: <frame_system::Pallet<T>>::block_number(),
+			};
+
+			ContentAttestations::<T>::insert(upload_id, attestation);
+			PendingAttestations::<T>::remove(upload_id);
+			Self::deposit_event(Event::ContentAttested { uid: upload_id, available, intact });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1).ref_time())]
+		pub fn submit_content_audit(
+			origin: OriginFor<T>,
+			check: ContentCheck<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let ContentCheck { upload_id, available, intact, .. } = check;
+
+			let upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			ensure!(upload.status == UploadStatus::Verified, Error::<T>::NotVerified);
+
 			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(!Self::audited_this_epoch(upload_id, now), Error::<T>::AlreadyAuditedThisEpoch);
+
+			let mut audit = Self::get_content_audit(upload_id).unwrap_or(ContentAudit::<T> {
+				last_audited: now,
+				last_seen_available: None,
+				consecutive_failures: 0,
+				at_risk: false,
+			});
+			audit.last_audited = now;
+
+			if available && intact {
+				audit.last_seen_available = Some(now);
+				audit.consecutive_failures = 0;
+				audit.at_risk = false;
+			} else {
+				audit.consecutive_failures = audit.consecutive_failures.saturating_add(1);
+				if !audit.at_risk && audit.consecutive_failures >= T::MaxAuditFailures::get() {
+					audit.at_risk = true;
+					Self::deposit_event(Event::ContentUnavailable { uid: upload_id, failures: audit.consecutive_failures });
+				}
+			}
 
-			let member = Member::<T> {
-				member_id: uid.clone(),
-				metadata: metadata,
-				vote_count: 0,
-				approved_contributions: 0,
-				role: Roles::Qualifier,
-				joined: now,
+			ContentAudits::<T>::insert(upload_id, audit);
+			Self::deposit_event(Event::ContentAudited { uid: upload_id, available, intact });
+
+			Ok(())
+		}
+
+		/// Record the gateway check of the content proposed by the pending amendment of
+		/// `check.upload_id`. The amendment can only pass if the content was found intact.
+		#[pallet::call_index(57)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1).ref_time())]
+		pub fn submit_amendment_attestation(
+			origin: OriginFor<T>,
+			check: ContentCheck<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let ContentCheck { upload_id, available, intact, .. } = check;
+
+			let uid = Self::get_pending_amendment(upload_id).ok_or(Error::<T>::AmendmentNotFound)?;
+			ensure!(!AmendmentAttestations::<T>::contains_key(uid), Error::<T>::ContentAlreadyAttested);
+
+			let attestation = ContentAttestation::<T> {
+				available,
+				intact,
+				checked_at: <frame_system::Pallet<T>>::block_number(),
 			};
 
-			Members::<T>::insert(who.clone(),&member);
-			MembersCount::<T>::put(uid.clone());
+			AmendmentAttestations::<T>::insert(uid, attestation);
+			Self::deposit_event(Event::AmendmentAttested { uid, upload_id, available, intact });
 
-			Self::deposit_event(Event::MemberAdded { who, uid });
+			Ok(())
+		}
+	}
+
+	// Helpful functions
+	impl<T: Config> Pallet<T> {
+		/// Open the Qualification ballot of a new upload.
+		fn start_qualification_vote(uid: u64) -> DispatchResult {
+			let (start, end) = Self::ballot_window(VoteType::Qualification);
+
+			let vote = Vote::<T>::new(start, end);
+
+			let vote_type = VoteType::Qualification;
+
+			Votes::<T>::insert((vote_type,uid),vote);
+			PendingAttestations::<T>::insert(uid, ());
+
+			Self::deposit_event(Event::NewVote { vote_type, uid});
 
 			Ok(())
+		}
+
+		/// Open the Verification ballot of an upload that passed Qualification.
+		pub fn start_verification_vote(uid: u64) -> DispatchResult {
+			let mut upload = Self::get_upload(uid).ok_or(Error::<T>::UploadNotFound)?;
+			let (start, end) = Self::ballot_window(VoteType::Verification);
 
+			let new_vote = Vote::<T>::new(start, end);
+
+			let vote_type = VoteType::Verification;
+
+			Votes::<T>::insert((vote_type,uid),new_vote);
+			upload.status = UploadStatus::VerificationVoteInProgress;
+			Uploads::<T>::insert(uid,&upload);
+
+			Self::deposit_event(Event::NewVote { vote_type: vote_type,uid});
+			Ok(())
 		}
 
-		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,3).ref_time())]
-		pub fn upload_document(origin: OriginFor<T>, hash: Vec<u8>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		/// Put an upload that passed Verification under expert review.
+		pub fn start_expert_review(uid: u64) -> DispatchResult {
+			let mut upload = Self::get_upload(uid).ok_or(Error::<T>::UploadNotFound)?;
+			upload.status = UploadStatus::UnderExpertReview;
+			Uploads::<T>::insert(uid,&upload);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let end = now + Self::expert_review_window().into();
+
+			// Prefer Experts in the upload's subject categories when there are any.
+			let pool = Self::expert_pool(&upload.creator);
+			let matched = pool.iter()
+				.filter(|who| upload.categories.iter().any(|domain| Self::is_domain_expert(*domain, *who)))
+				.cloned()
+				.collect::<Vec<_>>();
+			let candidates = if matched.is_empty() { pool } else { matched };
+			let panel = Self::draw_panel(&(b"bhdao/review", uid).encode(), candidates, T::ReviewPanelSize::get());
+
+			let expert_review = ExpertReview::<T> {
+				start: now,
+				end: end,
+				objections: None,
+				panel: panel.clone(),
+				responded: Vec::new(),
+			};
+
+			ExpertReviews::<T>::insert(uid,&expert_review);
+			Self::take_seats(uid, &panel);
+			Self::deposit_event(Event::ExpertReviewStarted { uid });
+			Self::deposit_event(Event::ReviewPanelAssigned { uid, panel });
+			Ok(())
+		}
+
+		/// Mark an upload Verified and mint its token to the contributor and co-contributors,
+		/// in proportion to their shares.
+		pub fn verify_upload(upload_id: u64) -> DispatchResult {
+			// Whichever path verifies a sensitive upload, its subjects must have consented.
+			ensure!(Self::missing_consents(upload_id).is_empty(), Error::<T>::MissingConsent);
+			let mut upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			upload.status = UploadStatus::Verified;
+			Uploads::<T>::insert(upload_id.clone(),&upload);
+			let exist = Approved::<T>::exists();
+			let token_id = Self::token_uid_count().checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
+			let tuid: TokenIdOf<T> = token_id.try_into().ok().unwrap();
+			let share1 = Self::get_contributor_share();
+
+			let royalty_split = Self::contributor_split(upload_id, &upload.creator);
+			let (tos, amounts) = Self::split_share(&royalty_split, share1);
+			pallet_nft::Pallet::<T>::_mint_batch(tos,tuid,amounts,upload.hash.clone())?;
+			pallet_nft::Pallet::<T>::_set_royalty_split(tuid, royalty_split)?;
+			if let Some(license) = Self::get_upload_license(upload_id) {
+				pallet_nft::Pallet::<T>::_set_token_license(tuid, license)?;
+			}
+			TokenCount::<T>::put(tuid);
+			UploadTokens::<T>::insert(upload_id, tuid);
+			if let Some(collection) = Self::get_upload_collection(upload_id) {
+				pallet_nft::Pallet::<T>::_add_to_collection(collection, tuid)?;
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			UploadVersions::<T>::append(upload_id, UploadVersion::<T> { hash: upload.hash.clone(), amendment: None, at: now });
+			EarlyAccess::<T>::insert(tuid, now + T::CollectorEarlyAccess::get().into());
+			Self::release_sponsorships(upload_id, Some(&upload.creator));
+
+			if let Some(mut member) = Self::get_member(upload.creator.clone()) {
+				member.approved_contributions = member.approved_contributions.saturating_add(1);
+				Members::<T>::insert(&upload.creator,&member);
+			}
+			Self::adjust_reputation(&upload.creator, true, T::VerifiedUploadReward::get());
+
+			match exist {
+				true => {
+					let mut temp = Approved::<T>::get();
+					temp.push(tuid);
+					Approved::<T>::put(temp);
+				},
+				false => {
+					let mut temp: Vec<TokenIdOf<T>> = Vec::new();
+					temp.push(tuid);
+					Approved::<T>::put(temp);
+				}
+			};
+
+			Ok(())
+		}
+
+		/// Subjects of a sensitive upload who have not consented yet.
+		pub fn missing_consents(uid: u64) -> Vec<T::AccountId> {
+			Self::get_subjects(uid).unwrap_or_default()
+				.into_iter()
+				.filter(|who| !Consents::<T>::contains_key(uid, who))
+				.collect()
+		}
+
+		/// Redact the upload of a passed takedown: clear its content hashes and metadata from
+		/// state, keep a tombstone hash of the content and freeze its token.
+		pub fn redact_upload(uid: u64) -> DispatchResult {
+			let mut takedown = Self::get_takedown(uid).ok_or(Error::<T>::TakedownNotFound)?;
+			let upload_id = takedown.upload_id;
+			let mut upload = Self::get_upload(upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			let tombstone = T::Hashing::hash(&upload.hash);
+			upload.hash = Vec::new();
+			upload.categories = Vec::new();
+			upload.status = UploadStatus::Redacted;
+			Uploads::<T>::insert(upload_id, &upload);
+			Tombstones::<T>::insert(upload_id, tombstone);
+
+			// Earlier versions and every amendment proposed, applied or not, name the content too.
+			UploadVersions::<T>::remove(upload_id);
+			for amendment_id in Self::get_upload_amendments(upload_id) {
+				Amendments::<T>::mutate(amendment_id, |amendment| {
+					if let Some(amendment) = amendment {
+						amendment.hash = Vec::new();
+						amendment.reason = Vec::new();
+					}
+				});
+				AmendmentAttestations::<T>::remove(amendment_id);
+			}
+			ContentAttestations::<T>::remove(upload_id);
+			SensitiveUploads::<T>::remove(upload_id);
+			let _ = Consents::<T>::clear_prefix(upload_id, T::MaxSubjects::get(), None);
+
+			if let Some(token) = Self::get_upload_token(upload_id) {
+				pallet_nft::Pallet::<T>::_set_token_uri(token, Vec::new())?;
+				if !pallet_nft::Pallet::<T>::is_frozen(token) {
+					pallet_nft::Pallet::<T>::_freeze_token(token)?;
+				}
+			}
+
+			takedown.status = VoteStatus::Passed;
+			Takedowns::<T>::insert(uid, &takedown);
+			PendingTakedowns::<T>::remove(upload_id);
+
+			Self::deposit_event(Event::UploadRedacted { uid: upload_id, takedown_id: uid, tombstone });
+			Ok(())
+		}
+
+		pub fn reject_takedown(uid: u64) -> DispatchResult {
+			let mut takedown = Self::get_takedown(uid).ok_or(Error::<T>::TakedownNotFound)?;
+			takedown.status = VoteStatus::Failed;
+			Takedowns::<T>::insert(uid, &takedown);
+			PendingTakedowns::<T>::remove(takedown.upload_id);
+			let until = <frame_system::Pallet<T>>::block_number() + T::TakedownCooldown::get().into();
+			TakedownCooldowns::<T>::insert(takedown.upload_id, until);
+			// Only lift a freeze the takedown placed itself.
+			if let Some(token) = Self::get_upload_token(takedown.upload_id) {
+				if takedown.froze_token && pallet_nft::Pallet::<T>::is_frozen(token) {
+					pallet_nft::Pallet::<T>::_thaw_token(token)?;
+				}
+			}
+
+			Self::deposit_event(Event::TakedownRejected { uid, upload_id: takedown.upload_id });
+			Ok(())
+		}
+
+		/// Contributors of an upload with their shares in basis points, creator first.
+		pub fn contributor_split(uid: u64, creator: &T::AccountId) -> Vec<(T::AccountId,u16)> {
+			let co_contributors = Self::get_co_contributors(uid);
+			let taken = co_contributors.iter().fold(0u16, |taken, (_,share)| taken.saturating_add(*share));
+			let mut split = Vec::with_capacity(co_contributors.len() + 1);
+			split.push((creator.clone(), 10_000u16.saturating_sub(taken)));
+			split.extend(co_contributors);
+			split
+		}
+
+		/// Divide `total` by basis-point shares. The first account takes any rounding remainder, and
+		/// accounts whose part rounds to zero are left out.
+		fn split_share(split: &[(T::AccountId,u16)], total: BalanceOf<T>) -> (Vec<T::AccountId>, Vec<BalanceOf<T>>) {
+			let mut parts = split.iter()
+				.map(|(account,share)| (account.clone(), Perbill::from_rational(*share as u32, 10_000u32) * total))
+				.collect::<Vec<_>>();
+			let minted = parts.iter().fold(Zero::zero(), |minted: BalanceOf<T>, (_,amount)| minted.saturating_add(*amount));
+			if let Some((_, first)) = parts.first_mut() {
+				*first = first.saturating_add(total.saturating_sub(minted));
+			}
+			parts.into_iter().filter(|(_,amount)| !amount.is_zero()).unzip()
+		}
+
+		/// Withdraw an upload that never opened its review and return its sponsorships.
+		fn withdraw_upload(uid: u64) -> DispatchResult {
+			let mut upload = Self::get_upload(uid).ok_or(Error::<T>::UploadNotFound)?;
+			upload.status = UploadStatus::Withdrawn;
+			Uploads::<T>::insert(uid, &upload);
+			PendingCoContributors::<T>::remove(uid);
+			CoContributors::<T>::remove(uid);
+			Self::release_sponsorships(uid, None);
+			Self::deposit_event(Event::UploadWithdrawn { uid });
+			Ok(())
+		}
+
+		/// Create an upload by `who` in `status`, checking they may contribute it.
+		fn add_upload(who: &T::AccountId, hash: Vec<u8>, categories: Vec<u32>, license: Option<u32>, status: UploadStatus) -> Result<u64, DispatchError> {
+			Self::ensure_not_suspended(who)?;
 			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
 
-			ensure!(member.role == Roles::Contributor, Error::<T>::NotEligibleToContribute);
+			// Collectors commission verification of their own holdings.
+			ensure!(member.role == Roles::Contributor || member.role == Roles::Collector, Error::<T>::NotEligibleToContribute);
+			ensure!(categories.len() as u32 <= T::MaxCategories::get(), Error::<T>::TooManyCategories);
+			ensure!(categories.iter().all(|domain| Domains::<T>::contains_key(domain)), Error::<T>::UnknownDomain);
+			if let Some(license) = license {
+				Self::ensure_license_available(license)?;
+			}
 
 			let uid = Self::upload_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
 
 			let upload = Upload::<T> {
 				creator: who.clone(),
 				hash: hash,
-				status: UploadStatus::QualificationVoteInProgress,
+				status,
+				categories,
 			};
 
 			Uploads::<T>::insert(uid.clone(),upload);
 			UploadCount::<T>::put(uid.clone());
-
+			if let Some(license) = license {
+				UploadLicenses::<T>::insert(uid, license);
+			}
 
 			Self::deposit_event(Event::NewUpload { uid });
+			Ok(uid)
+		}
 
-			let now = <frame_system::Pallet<T>>::block_number();
+		fn curated_collection(who: &T::AccountId, uid: u64) -> Result<Collection<T>, DispatchError> {
+			let collection = Self::get_collection(uid).ok_or(Error::<T>::CollectionNotFound)?;
+			ensure!(&collection.curator == who, Error::<T>::NotCurator);
+			Ok(collection)
+		}
 
-			let end = now + T::VotingWindow::get().into();
+		/// Append `upload_id` to `collection`. The caller stores the collection.
+		fn collect(collection: &mut Collection<T>, collection_id: u64, upload_id: u64) -> DispatchResult {
+			ensure!(!UploadCollection::<T>::contains_key(upload_id), Error::<T>::AlreadyInCollection);
+			ensure!((collection.uploads.len() as u32) < T::MaxCollectionSize::get(), Error::<T>::CollectionFull);
+			collection.uploads.push(upload_id);
+			UploadCollection::<T>::insert(upload_id, collection_id);
+			Self::deposit_event(Event::AddedToCollection { uid: collection_id, upload_id });
+			Ok(())
+		}
 
-			let vote = Vote::<T> {
-				yes_votes: 0,
-				no_votes: 0,
-				start: now,
-				end: end,
-				status: VoteStatus::InProgress,
-			};
+		/// Send every upload a collection put to review on to its own expert review, or reject them
+		/// all, then reopen the collection.
+		pub fn review_collection(uid: u64, passed: bool) -> DispatchResult {
+			let mut collection = Self::get_collection(uid).ok_or(Error::<T>::CollectionNotFound)?;
+			for upload_id in collection.uploads.iter() {
+				if Self::get_upload(upload_id).map_or(true, |upload| upload.status != UploadStatus::InCollection) {
+					continue
+				}
+				if passed {
+					Self::start_expert_review(*upload_id)?;
+				} else {
+					Self::reject_upload(*upload_id)?;
+				}
+			}
 
-			let vote_type = VoteType::Qualification;
+			collection.status = CollectionStatus::Open;
+			Collections::<T>::insert(uid, collection);
+			Self::deposit_event(Event::CollectionReviewed { uid, passed });
+			Ok(())
+		}
 
-			Votes::<T>::insert((vote_type,uid.clone()),vote);
+		/// Tokens minted for the uploads of a collection.
+		pub fn collection_tokens(uid: u64) -> Vec<TokenIdOf<T>> {
+			pallet_nft::Pallet::<T>::tokens_of_collection(uid)
+		}
 
-			Self::deposit_event(Event::NewVote { vote_type, uid});
+		fn ensure_license_available(license: u32) -> DispatchResult {
+			ensure!(Licenses::<T>::contains_key(license) && !Self::is_license_retired(license), Error::<T>::UnknownLicense);
+			Ok(())
+		}
 
+		fn ensure_not_redacted(uid: u64) -> DispatchResult {
+			ensure!(Self::get_upload(uid).map_or(true, |upload| upload.status != UploadStatus::Redacted), Error::<T>::UploadIsRedacted);
 			Ok(())
 		}
 
-		#[pallet::call_index(2)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,3).ref_time())]
-		pub fn cast_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: bool) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check if member
-			let mut member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			// Check Vote Type
-			ensure!(vote_type == VoteType::Verification || vote_type == VoteType::Qualification, Error::<T>::WrongVoteType);
-			// Check the role
-			if vote_type == VoteType::Verification {
-				ensure!(member.role == Roles::Verifier,Error::<T>::NotEligibleToVerify);
+		/// License an upload is published under.
+		pub fn upload_license(uid: u64) -> Option<License<T::Hash>> {
+			Self::get_upload_license(uid).and_then(|license| Self::get_license(license))
+		}
+
+		/// License a minted token is published under.
+		pub fn token_license(token: TokenIdOf<T>) -> Option<License<T::Hash>> {
+			pallet_nft::Pallet::<T>::get_token_license(token).and_then(|license| Self::get_license(license))
+		}
+
+		/// Make the content of a passed amendment the canonical version of its upload.
+		pub fn apply_amendment(uid: u64) -> DispatchResult {
+			let mut amendment = Self::get_amendment(uid).ok_or(Error::<T>::AmendmentNotFound)?;
+			let mut upload = Self::get_upload(amendment.upload_id).ok_or(Error::<T>::UploadNotFound)?;
+			upload.hash = amendment.hash.clone();
+			Uploads::<T>::insert(amendment.upload_id, &upload);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			UploadVersions::<T>::append(amendment.upload_id, UploadVersion::<T> { hash: amendment.hash.clone(), amendment: Some(uid), at: now });
+			let version = UploadVersions::<T>::decode_len(amendment.upload_id).unwrap_or_default() as u32;
+			if let Some(token) = Self::get_upload_token(amendment.upload_id) {
+				pallet_nft::Pallet::<T>::_set_token_uri(token, amendment.hash.clone())?;
 			}
 
-			// Check if the vote exists
-			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+			amendment.status = VoteStatus::Passed;
+			Amendments::<T>::insert(uid, &amendment);
+			PendingAmendments::<T>::remove(amendment.upload_id);
 
-			
+			Self::deposit_event(Event::UploadAmended { uid: amendment.upload_id, amendment_id: uid, version });
+			Ok(())
+		}
+
+		/// Close a failed amendment, leaving its upload as it was.
+		pub fn reject_amendment(uid: u64) -> DispatchResult {
+			let mut amendment = Self::get_amendment(uid).ok_or(Error::<T>::AmendmentNotFound)?;
+			amendment.status = VoteStatus::Failed;
+			Amendments::<T>::insert(uid, &amendment);
+			PendingAmendments::<T>::remove(amendment.upload_id);
+
+			Self::deposit_event(Event::AmendmentRejected { uid, upload_id: amendment.upload_id });
+			Ok(())
+		}
+
+		/// Reject an upload, remembering the stage it was at for an appeal.
+		pub fn reject_upload(uid: u64) -> DispatchResult {
+			let mut upload = Self::get_upload(uid).ok_or(Error::<T>::UploadNotFound)?;
+			let rejection = Rejection::<T> {
+				stage: upload.status,
+				at: <frame_system::Pallet<T>>::block_number(),
+				appealed: false,
+			};
+			upload.status = UploadStatus::Rejected;
+			Uploads::<T>::insert(uid,&upload);
+			Rejections::<T>::insert(uid,rejection.clone());
+			Self::release_sponsorships(uid, None);
+			let penalty = Self::adjust_reputation(&upload.creator, false, T::RejectedUploadPenalty::get());
+			RejectionPenalties::<T>::insert(uid, penalty);
+
+			Self::deposit_event(Event::UploadRejected { uid, stage: rejection.stage });
+			Ok(())
+		}
+
+		/// Reputation of `who` with decay applied up to now.
+		pub fn reputation_of(who: &T::AccountId) -> u32 {
+			Self::get_reputation(who).map_or(0, |reputation| Self::decayed(reputation).score)
+		}
+
+		fn decayed(mut reputation: Reputation<T>) -> Reputation<T> {
 			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > vote.start && now < vote.end, Error::<T>::VotingWindowNotValid);
+			let period: T::BlockNumber = T::ReputationDecayPeriod::get().into();
+			if period.is_zero() {
+				return reputation
+			}
+			let steps = (now.saturating_sub(reputation.updated) / period).saturated_into::<u32>();
+			let retained = Perbill::one().saturating_sub(T::ReputationDecay::get()).saturating_pow(steps as usize);
+			reputation.score = retained * reputation.score;
+			reputation.updated = reputation.updated.saturating_add(period.saturating_mul(steps.into()));
+			reputation
+		}
 
-			if vote_cast {
-				vote.yes_votes = vote.yes_votes + 1;
-			} else {
-				vote.no_votes = vote.no_votes + 1;
+		/// Credit or debit `amount` of reputation to `who`, promoting Qualifiers who meet the
+		/// Contributor requirements. Returns the change actually made, as the score can't go
+		/// below zero.
+		pub fn adjust_reputation(who: &T::AccountId, credit: bool, amount: u32) -> u32 {
+			if amount == 0 {
+				return 0
 			}
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut reputation = Self::decayed(Self::get_reputation(who).unwrap_or(Reputation::<T> { score: 0, updated: now }));
+			let before = reputation.score;
+			reputation.score = match credit {
+				true => reputation.score.saturating_add(amount),
+				false => reputation.score.saturating_sub(amount),
+			};
+			let score = reputation.score;
+			Reputations::<T>::insert(who, reputation);
+			Self::deposit_event(Event::ReputationChanged { who: who.clone(), score });
+
+			if let Some(mut member) = Self::get_member(who.clone()) {
+				if Self::unmet_requirements(who, Roles::Contributor).is_empty() {
+					member.role = Roles::Contributor;
+					Members::<T>::insert(who,&member);
+					Self::deposit_event(Event::MemberPromoted { who: who.clone(), role: Roles::Contributor });
+				}
+			}
+			score.abs_diff(before)
+		}
 
-			Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-			CheckVote::<T>::insert((who.clone(),vote_type.clone(),voting_id.clone()),true);
+		pub fn ballot_timing(vote_type: VoteType) -> BallotTiming {
+			Self::get_ballot_timing(vote_type).unwrap_or(BallotTiming {
+				window: T::VotingWindow::get(),
+				delay: 0,
+				late_period: 0,
+				extension: 0,
+				max_extensions: 0,
+			})
+		}
 
-			let vote_count = member.vote_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-			
-			if vote_count == 10 {
-				member.role = Roles::Contributor;
-			}
+		/// Start and end of a `vote_type` ballot opened now.
+		pub fn ballot_window(vote_type: VoteType) -> (T::BlockNumber, T::BlockNumber) {
+			let timing = Self::ballot_timing(vote_type);
+			let start = <frame_system::Pallet<T>>::block_number() + timing.delay.into();
+			(start, start + timing.window.into())
+		}
 
-			member.vote_count = vote_count;
+		pub fn expert_review_window() -> u32 {
+			Self::get_expert_review_window().unwrap_or_else(T::VotingWindow::get)
+		}
 
-			Members::<T>::insert(who.clone(),&member);
+		/// Push back the end of a ballot voted on in its last `late_period` blocks, along with
+		/// the reveal window of a commit-reveal ballot.
+		pub fn extend_if_late(vote_type: VoteType, uid: u64, vote: &mut Vote<T>) {
+			let timing = Self::ballot_timing(vote_type);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let extensions = Self::get_ballot_extensions((vote_type,uid));
+			if timing.extension == 0 || extensions >= timing.max_extensions || now + timing.late_period.into() < vote.end {
+				return
+			}
 
-			Self::deposit_event(Event::VoteCast { vote_type: vote_type, uid: voting_id});
+			vote.end = vote.end + timing.extension.into();
+			BallotExtensions::<T>::insert((vote_type,uid), extensions + 1);
+			SecretBallots::<T>::mutate((vote_type,uid), |ballot| {
+				if let Some(ballot) = ballot {
+					ballot.reveal_end = ballot.reveal_end + timing.extension.into();
+				}
+			});
+			Self::deposit_event(Event::BallotExtended { vote_type, uid, end: vote.end });
+		}
 
-			Ok(())
+		/// Requirements for `role` as set by governance, or the built-in progression
+		/// Qualifier → Contributor → Verifier → Expert, with Collectors coming from Qualifiers.
+		pub fn role_requirement(role: Roles) -> RoleRequirement {
+			Self::get_role_requirement(role).unwrap_or_else(|| Self::default_requirement(role))
 		}
 
-		#[pallet::call_index(3)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,4).ref_time())]
-		pub fn finalize_vote(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check if member
-			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			// Check if the vote exists
-			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+		pub fn default_requirement(role: Roles) -> RoleRequirement {
+			let from_role = match role {
+				Roles::Contributor | Roles::Collector => Roles::Qualifier,
+				Roles::Verifier => Roles::Contributor,
+				Roles::Expert => Roles::Verifier,
+				Roles::None | Roles::Qualifier => Roles::None,
+			};
+			RoleRequirement {
+				from_role,
+				min_tenure: 0,
+				min_reputation: if role == Roles::Contributor { T::ContributorThreshold::get() } else { 0 },
+				min_approved_contributions: 0,
+				cooldown: 0,
+			}
+		}
+
+		/// Requirements for `role` that `who` does not meet yet.
+		pub fn unmet_requirements(who: &T::AccountId, role: Roles) -> Vec<UnmetRequirement> {
+			let rule = Self::role_requirement(role);
+			let member = match Self::get_member(who.clone()) {
+				Some(member) => member,
+				None => return vec![UnmetRequirement::Role(rule.from_role)],
+			};
 			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > vote.end,Error::<T>::VoteStillInProgress);
-			// Check vote types
-			
+			let mut unmet = Vec::new();
+
+			// Experts apply again for further domains.
+			if member.role != rule.from_role && !(role == Roles::Expert && member.role == Roles::Expert) {
+				unmet.push(UnmetRequirement::Role(rule.from_role));
+			}
+			let tenure = now.saturating_sub(member.joined).saturated_into::<u32>();
+			if tenure < rule.min_tenure {
+				unmet.push(UnmetRequirement::Tenure(rule.min_tenure - tenure));
+			}
+			let reputation = Self::reputation_of(who);
+			if reputation < rule.min_reputation {
+				unmet.push(UnmetRequirement::Reputation(rule.min_reputation - reputation));
+			}
+			if member.approved_contributions < rule.min_approved_contributions {
+				unmet.push(UnmetRequirement::ApprovedContributions(rule.min_approved_contributions - member.approved_contributions));
+			}
+			if let Some(last) = Self::get_last_application(who) {
+				let since = now.saturating_sub(last).saturated_into::<u32>();
+				if since < rule.cooldown {
+					unmet.push(UnmetRequirement::Cooldown(rule.cooldown - since));
+				}
+			}
 
-			// ToDo : Add Quorum
+			unmet
+		}
 
-			// Votes
-			//let total_votes = vote.yes_votes + vote.no_votes;
+		/// Tally of a ballot, finalized or not.
+		pub fn ballot_tally(vote_type: VoteType, uid: u64) -> Option<Tally> {
+			Self::get_vote((vote_type, uid)).map(|vote| vote.tally)
+		}
 
-			match vote.yes_votes > vote.no_votes {
-				true => {
-					vote.status = VoteStatus::Passed;
-					Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-					Self::deposit_event(Event::VoteEnded { vote_type: vote_type, uid: voting_id});
-					if vote_type == VoteType::Qualification  {
-						let mut upload = Self::get_upload(voting_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
-						let now = <frame_system::Pallet<T>>::block_number();
-
-						let end = now + T::VotingWindow::get().into();
-
-						let new_vote = Vote::<T> {
-							yes_votes: 0,
-							no_votes: 0,
-							start: now,
-							end: end,
-							status: VoteStatus::InProgress,
-						};
-
-						let vote_type = VoteType::Verification;
-
-						Votes::<T>::insert((vote_type.clone(),voting_id.clone()),new_vote);
-						upload.status = UploadStatus::VerificationVoteInProgress;
-						Uploads::<T>::insert(voting_id.clone(),&upload);
-
-						Self::deposit_event(Event::NewVote { vote_type: vote_type,uid: voting_id});
-					} else if vote_type == VoteType::Verification  {
-						let mut upload = Self::get_upload(voting_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
-						//Changes
-						//upload.status = UploadStatus::Verified;
-						upload.status = UploadStatus::UnderExpertReview;
-						Uploads::<T>::insert(voting_id.clone(),&upload);
-
-						let now = <frame_system::Pallet<T>>::block_number();
-
-						let end = now + T::VotingWindow::get().into();
-
-						let expert_review = ExpertReview::<T> {
-							start: now,
-							end: end,
-							objections: None,
-						};
-
-						ExpertReviews::<T>::insert(voting_id.clone(),&expert_review);
-						Self::deposit_event(Event::ExpertReviewStarted { uid: voting_id});
-						
+		/// Unmet requirements of `who` for every role they could move into.
+		pub fn role_progress(who: &T::AccountId) -> Vec<(Roles, Vec<UnmetRequirement>)> {
+			[Roles::Contributor, Roles::Verifier, Roles::Expert, Roles::Collector]
+				.into_iter()
+				.map(|role| (role, Self::unmet_requirements(who, role)))
+				.collect()
+		}
+
+		/// Fail with `role_error` if `who` doesn't hold the role `role` is reached from, and
+		/// with `RequirementsNotMet` if anything else is missing.
+		pub fn ensure_meets_requirements(who: &T::AccountId, role: Roles, role_error: Error<T>) -> DispatchResult {
+			let unmet = Self::unmet_requirements(who, role);
+			ensure!(!unmet.iter().any(|requirement| matches!(requirement, UnmetRequirement::Role(_))), role_error);
+			ensure!(unmet.is_empty(), Error::<T>::RequirementsNotMet);
+			Ok(())
+		}
+
+		/// Credit everyone who voted with the outcome of a finalized ballot, up to
+		/// `MaxSettlements` votes now and the rest in idle blocks.
+		pub fn settle_ballot(vote_type: VoteType, uid: u64, passed: bool) {
+			BallotExtensions::<T>::remove((vote_type,uid));
+			PendingSettlements::<T>::append((vote_type, uid, passed));
+			Self::settle_pending(T::MaxSettlements::get());
+		}
+
+		/// Weight of one step of `settle_pending`: settling one vote, or finding a ballot has no
+		/// votes left.
+		pub fn settlement_weight() -> Weight {
+			// The vote itself, then the voter's reputation and membership, each read twice and
+			// written, and the role requirement and last application read for a promotion. Each
+			// of the up to two events is a read and a write.
+			T::DbWeight::get().reads_writes(7,3).saturating_add(T::DbWeight::get().reads_writes(2,2))
+		}
+
+		/// Take up to `limit` steps settling the votes of the pending ballots, oldest first, and
+		/// return how many were taken. Each settled vote and each ballot found empty is a step.
+		pub fn settle_pending(limit: u32) -> u32 {
+			let mut pending = Self::get_pending_settlements();
+			let mut settled = 0;
+			while let Some(&(vote_type, uid, passed)) = pending.first() {
+				if settled >= limit {
+					break
+				}
+				let votes = BallotVotes::<T>::iter_prefix((vote_type,uid))
+					.take(limit.saturating_sub(settled) as usize)
+					.collect::<Vec<_>>();
+				for (who, choice) in votes {
+					BallotVotes::<T>::remove((vote_type,uid), &who);
+					if choice.agrees_with(passed) {
+						Self::adjust_reputation(&who, true, T::AccurateVoteReward::get());
 					}
-				},
-				false => {
-					vote.status = VoteStatus::Failed;
-					Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-					Self::deposit_event(Event::VoteEnded { vote_type : vote_type, uid: voting_id});
-					if vote_type == VoteType::Qualification || vote_type == VoteType::Verification  {
-						let mut upload = Self::get_upload(voting_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
-						upload.status = UploadStatus::Rejected;
-						Uploads::<T>::insert(voting_id.clone(),&upload);
-					} 
+					settled += 1;
+				}
+				if settled >= limit {
+					break
 				}
+				pending.remove(0);
+				settled += 1;
 			}
+			PendingSettlements::<T>::put(pending);
+			settled
+		}
+
+		/// Whether `NoWithVeto` can be cast on ballots of `vote_type`: those decided by Experts,
+		/// and applications for the Expert role.
+		pub fn allows_veto(vote_type: VoteType) -> bool {
+			matches!(vote_type, VoteType::ExpertVerification | VoteType::ExpertCouncilApproval |
+				VoteType::CuratorCouncilApproval | VoteType::CollectorCouncilApproval | VoteType::Objection |
+				VoteType::Appeal | VoteType::Takedown | VoteType::Proposal)
+		}
+
+		/// Whether ballot `uid` was vetoed.
+		pub fn is_vetoed(vote_type: VoteType, uid: u64) -> bool {
+			Self::get_vote((vote_type, uid)).map_or(false, |vote| vote.status == VoteStatus::Vetoed)
+		}
+
+		fn ensure_choice_allowed(vote_type: VoteType, choice: VoteChoice) -> DispatchResult {
+			ensure!(choice != VoteChoice::NoWithVeto || Self::allows_veto(vote_type), Error::<T>::VetoNotAllowed);
+			Ok(())
+		}
+
+		/// Record the outcome of `vote`. A vetoed ballot fails whatever `passed` says. Returns
+		/// whether it passed.
+		fn conclude_ballot(vote_type: VoteType, vote: &mut Vote<T>, passed: bool) -> bool {
+			if Self::allows_veto(vote_type) && vote.tally.vetoed(T::VetoThreshold::get()) {
+				vote.veto();
+				return false
+			}
+			vote.conclude(passed);
+			passed
+		}
+
+		/// Return the deposit of role application `uid`, or slash it if the council vetoed it.
+		pub fn release_application_deposit(uid: u64, vetoed: bool) {
+			if let Some((who, amount)) = ApplicationDeposits::<T>::take(uid) {
+				if vetoed {
+					let _ = T::Currency::slash_reserved(&who, amount);
+					Self::deposit_event(Event::ApplicationDepositSlashed { uid, who, amount });
+				} else {
+					T::Currency::unreserve(&who, amount);
+				}
+			}
+		}
+
+		/// Pay out the sponsorships of an upload to `creator`, or return them to their sponsors.
+		fn release_sponsorships(uid: u64, creator: Option<&T::AccountId>) {
+			for (who, amount) in Sponsorships::<T>::drain_prefix(uid) {
+				let paid = match creator {
+					Some(creator) => T::Currency::repatriate_reserved(&who, creator, amount, BalanceStatus::Free).is_ok(),
+					None => false,
+				};
+				if !paid {
+					T::Currency::unreserve(&who, amount);
+				}
+				Self::deposit_event(Event::SponsorshipReleased { uid, who, amount, paid });
+			}
+		}
+
+		pub fn kyc_attestations(application_id: u64) -> u32 {
+			KycAttestations::<T>::iter_prefix(application_id).count() as u32
+		}
+
+		/// Whether `who` can acquire `token_id` yet: Collectors right away, everyone else once
+		/// the early access window after verification is over.
+		pub fn has_early_access(who: &T::AccountId, token_id: TokenIdOf<T>) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let is_collector = Self::get_member(who.clone()).map_or(false, |member| member.role == Roles::Collector);
+			is_collector || Self::get_early_access(token_id).map_or(true, |until| now >= until)
+		}
+
+		pub fn experts_in_domain(domain: u32) -> Vec<T::AccountId> {
+			ExpertDomains::<T>::iter_key_prefix(domain).collect()
+		}
+
+		pub fn domains_of(who: &T::AccountId) -> Vec<u32> {
+			(1..=Self::domain_uid_count()).filter(|domain| Self::is_domain_expert(domain, who)).collect()
+		}
+
+		/// Experts who can review the uploads of `creator`: everyone but the creator, those
+		/// who declared a conflict with them and suspended Experts.
+		pub fn expert_pool(creator: &T::AccountId) -> Vec<T::AccountId> {
+			Self::get_experts()
+				.into_iter()
+				.filter(|who| who != creator && !Self::has_conflict(who, creator) && !Self::is_suspended(who))
+				.collect()
+		}
+
+		pub fn add_expert(who: &T::AccountId) -> DispatchResult {
+			Experts::<T>::try_mutate(|experts| {
+				if !experts.contains(who) {
+					ensure!((experts.len() as u32) < T::MaxExperts::get(), Error::<T>::TooManyExperts);
+					experts.push(who.clone());
+				}
+				Ok(())
+			})
+		}
 
-			Ok(())
+		pub fn remove_expert(who: &T::AccountId) {
+			Experts::<T>::mutate(|experts| experts.retain(|expert| expert != who));
 		}
 
-		#[pallet::call_index(4)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn apply_for_expanded_role(origin: OriginFor<T>,applied_role: Roles) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check roles applied
-			ensure!(applied_role == Roles::Expert || applied_role == Roles::Verifier,Error::<T>::WrongRoleApplied);
-			// Check if member
-			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			let mut vote_type = VoteType::ExpertVerification;
+		/// Experts who may sit on an appeal panel for an upload: the upload's expert pool, less
+		/// anyone involved in its review or in an earlier appeal.
+		pub fn appeal_candidates(upload_id: u64, creator: &T::AccountId) -> Vec<T::AccountId> {
+			let review = Self::get_expert_review(upload_id);
+			let objections = review.as_ref().and_then(|review| review.objections.as_ref());
+			let earlier_panels = Self::get_upload_appeals(upload_id)
+				.into_iter()
+				.filter_map(Self::get_appeal)
+				.flat_map(|appeal| appeal.panel)
+				.collect::<Vec<_>>();
+
+			Self::expert_pool(creator)
+				.into_iter()
+				.filter(|who| {
+					!review.as_ref().map_or(false, |review| review.panel.contains(who)) &&
+						!CheckVote::<T>::contains_key((who.clone(), VoteType::Qualification, upload_id)) &&
+						!CheckVote::<T>::contains_key((who.clone(), VoteType::Verification, upload_id)) &&
+						!objections.into_iter().flatten().any(|objection| {
+							objection.objector == *who ||
+								CheckVote::<T>::contains_key((who.clone(), VoteType::Objection, objection.uid))
+						}) &&
+						!earlier_panels.contains(who)
+				})
+				.collect()
+		}
 
-			if applied_role == Roles::Expert {
-				ensure!(member.role == Roles::Verifier,Error::<T>::NotEligibleForExpertRole);
+		/// Draw up to `size` of `candidates` at random, seeded from `T::Randomness` with `subject`.
+		pub fn draw_panel(subject: &[u8], mut candidates: Vec<T::AccountId>, size: u32) -> Vec<T::AccountId> {
+			let (seed, _) = T::Randomness::random(subject);
+			let mut panel = Vec::new();
+			let mut draw: u32 = 0;
+			while !candidates.is_empty() && (panel.len() as u32) < size {
+				let pick = T::Hashing::hash_of(&(seed, draw));
+				let index = u32::decode(&mut pick.as_ref()).unwrap_or_default() as usize % candidates.len();
+				panel.push(candidates.swap_remove(index));
+				draw += 1;
 			}
+			panel
+		}
 
-			if applied_role == Roles::Verifier {
-				ensure!(member.role == Roles::Contributor,Error::<T>::NotEligibleForVerifierRole);
-				vote_type = VoteType::CuratorVerification;
+		/// Weight of a plain ballot cast by `member` on a `vote_type` ballot.
+		pub fn ballot_weight(who: &T::AccountId, member: &Member<T>, vote_type: VoteType) -> Result<u128, DispatchError> {
+			match Self::get_vote_weighting(vote_type) {
+				VoteWeighting::OneMemberOneVote => Ok(1),
+				VoteWeighting::Reputation => Ok(Self::reputation_weight(who, member)),
+				VoteWeighting::Conviction => Err(Error::<T>::ConvictionVoteRequired.into()),
 			}
-			
-
-			let uid = Self::role_application_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
-
-			let now = <frame_system::Pallet<T>>::block_number();
+		}
 
-			let end = now + T::VotingWindow::get().into();
+		/// Weight of delegated power under the vote type's current weighting. Conviction needs
+		/// locked balance, so delegated power doesn't count there.
+		pub fn power_weight(vote_type: VoteType, power: DelegatedPower) -> u128 {
+			match Self::get_vote_weighting(vote_type) {
+				VoteWeighting::OneMemberOneVote => power.delegators as u128,
+				VoteWeighting::Reputation => power.reputation,
+				VoteWeighting::Conviction => 0,
+			}
+		}
 
-			let vote = Vote::<T> {
-				yes_votes: 0,
-				no_votes: 0,
-				start: now,
-				end: end,
-				status: VoteStatus::InProgress,
-			};
+		/// Weight delegated to `who` that counts on ballot `voting_id`.
+		pub fn delegated_weight(who: &T::AccountId, vote_type: VoteType, voting_id: u64) -> u128 {
+			let power = Self::get_delegated_power(who, vote_type)
+				.saturating_sub(Self::get_overridden_power((vote_type, voting_id), who));
+			Self::power_weight(vote_type, power)
+		}
 
+		/// Count `who`'s `choice` with `weight` on open ballot `uid`. Every way of voting directly
+		/// goes through here so the window, late extension and bookkeeping rules can't drift
+		/// apart. Secret ballots only take votes through `reveal_vote`.
+		pub fn record_ballot_vote(vote_type: VoteType, uid: u64, who: &T::AccountId, choice: VoteChoice, weight: u128) -> DispatchResult {
+			let mut vote = Self::get_vote((vote_type,uid)).ok_or(Error::<T>::VoteNotFound)?;
+			ensure!(!SecretBallots::<T>::contains_key((vote_type,uid)), Error::<T>::CommitRevealRequired);
+			let now = <frame_system::Pallet<T>>::block_number();
+			vote.ensure_open(now).map_err(Error::<T>::from)?;
 
-			Votes::<T>::insert((vote_type,uid.clone()),vote);
-			ExpertApplication::<T>::insert(uid.clone(),who.clone());
-			ApplicationCount::<T>::put(uid.clone());
+			Self::count_ballot_vote(vote_type, uid, who, choice, weight, &mut vote)?;
+			Self::extend_if_late(vote_type, uid, &mut vote);
+			Votes::<T>::insert((vote_type,uid), vote);
+			Ok(())
+		}
 
-			Self::deposit_event(Event::NewVote { vote_type, uid});
+		/// Count a vote revealed on secret ballot `uid`, once its commit window has closed.
+		pub fn record_revealed_vote(vote_type: VoteType, uid: u64, who: &T::AccountId, choice: VoteChoice, weight: u128) -> DispatchResult {
+			let mut vote = Self::get_vote((vote_type,uid)).ok_or(Error::<T>::VoteNotFound)?;
+			let ballot = Self::get_secret_ballot((vote_type,uid)).ok_or(Error::<T>::NotCommitReveal)?;
+			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(ballot.is_revealing(&vote, now), Error::<T>::RevealWindowNotValid);
 
+			Self::count_ballot_vote(vote_type, uid, who, choice, weight, &mut vote)?;
+			Votes::<T>::insert((vote_type,uid), vote);
 			Ok(())
 		}
 
-		#[pallet::call_index(5)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn cast_vote_for_expanded_role(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64, vote_cast: bool) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check if member
-			let mut member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			// Check Vote Type
-			ensure!(vote_type == VoteType::ExpertVerification || vote_type == VoteType::ExpertCouncilApproval ||
-				 vote_type == VoteType::CuratorVerification || vote_type == VoteType::CuratorCouncilApproval, Error::<T>::WrongVoteType);
+		/// Add `who`'s vote to the tally and to their record.
+		fn count_ballot_vote(vote_type: VoteType, uid: u64, who: &T::AccountId, choice: VoteChoice, weight: u128, vote: &mut Vote<T>) -> DispatchResult {
+			ensure!(!CheckVote::<T>::contains_key((who.clone(),vote_type,uid)), Error::<T>::AlreadyVoted);
+			Self::ensure_choice_allowed(vote_type, choice)?;
 
-			// Check if the vote exists
-			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
-			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > vote.start && now < vote.end, Error::<T>::VotingWindowNotValid);
+			vote.tally.add(choice, weight);
+			CheckVote::<T>::insert((who.clone(),vote_type,uid), true);
+			BallotVotes::<T>::insert((vote_type,uid), who, choice);
 
-			// Check the role
-			if vote_type == VoteType::ExpertVerification || vote_type == VoteType::CuratorVerification {
-				ensure!(member.role == Roles::Verifier,Error::<T>::NotEligibleToVerify);
-			}
+			Members::<T>::try_mutate(who, |member| -> DispatchResult {
+				let member = member.as_mut().ok_or(Error::<T>::NotAMember)?;
+				member.vote_count = member.vote_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})
+		}
 
-			if vote_type == VoteType::ExpertCouncilApproval || vote_type == VoteType::CuratorCouncilApproval {
-				ensure!(member.role == Roles::Expert,Error::<T>::NotAnExpert);
-			}
+		/// Whether voting power on `vote_type` ballots can be delegated.
+		pub fn is_delegable(vote_type: VoteType) -> bool {
+			matches!(vote_type, VoteType::Qualification | VoteType::Verification)
+		}
 
-			// Check if the vote exists
-			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+		/// Take `who`'s delegated power back from its delegate on a ballot `who` votes on directly.
+		fn withdraw_from_delegate(who: &T::AccountId, vote_type: VoteType, voting_id: u64) {
+			let delegation = match Self::get_delegation(who, vote_type) {
+				Some(delegation) => delegation,
+				None => return,
+			};
 
-			
-			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > vote.start && now < vote.end, Error::<T>::VotingWindowNotValid);
+			let mut delegate_ballots = Self::prune_open_ballots(&delegation.delegate, vote_type);
 
-			if vote_cast {
-				vote.yes_votes = vote.yes_votes + 1;
-			} else {
-				vote.no_votes = vote.no_votes + 1;
+			match delegate_ballots.iter_mut().find(|(uid, _, _)| *uid == voting_id) {
+				Some(ballot) => {
+					let weight = Self::power_weight(vote_type, delegation.power);
+					Self::move_delegated_weight(vote_type, ballot, weight, false);
+					OpenBallots::<T>::insert(&delegation.delegate, vote_type, &delegate_ballots);
+				},
+				None => OverriddenPower::<T>::mutate((vote_type, voting_id), &delegation.delegate, |p| {
+					*p = p.saturating_add(delegation.power)
+				}),
 			}
+		}
 
-			Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-			CheckVote::<T>::insert((who.clone(),vote_type.clone(),voting_id.clone()),true);
+		fn do_undelegate(who: &T::AccountId, vote_type: VoteType) -> DispatchResult {
+			let delegation = Self::get_delegation(who, vote_type).ok_or(Error::<T>::NotDelegating)?;
+			let delegate = delegation.delegate;
+			let power = delegation.power;
 
-			let vote_count = member.vote_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-			
-			member.vote_count = vote_count;
+			DelegatedPowers::<T>::mutate(&delegate, vote_type, |p| *p = p.saturating_sub(power));
+			Delegators::<T>::mutate(&delegate, vote_type, |delegators| delegators.retain(|d| d != who));
 
-			Members::<T>::insert(who.clone(),&member);
+			let weight = Self::power_weight(vote_type, power);
+			let mut delegate_ballots = Self::prune_open_ballots(&delegate, vote_type);
+			for ballot in delegate_ballots.iter_mut() {
+				if !CheckVote::<T>::contains_key((who.clone(), vote_type, ballot.0)) {
+					Self::move_delegated_weight(vote_type, ballot, weight, false);
+				}
+			}
+			OpenBallots::<T>::insert(&delegate, vote_type, &delegate_ballots);
+			for (uid, _, _) in Self::prune_open_ballots(who, vote_type) {
+				if !delegate_ballots.iter().any(|(d_uid, _, _)| *d_uid == uid) {
+					OverriddenPower::<T>::mutate((vote_type, uid), &delegate, |p| *p = p.saturating_sub(power));
+				}
+			}
 
-			Self::deposit_event(Event::VoteCast { vote_type: vote_type, uid: voting_id});
+			Delegations::<T>::remove(who, vote_type);
+			Self::deposit_event(Event::Undelegated { who: who.clone(), vote_type });
 
 			Ok(())
 		}
 
-		#[pallet::call_index(6)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn finalize_vote_for_expanded_role(origin: OriginFor<T>, vote_type: VoteType, voting_id: u64) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check if member
-			let mut member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			// Check if the vote exists
-			let mut vote = Self::get_vote((vote_type.clone(),voting_id.clone())).ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.status == VoteStatus::InProgress, Error::<T>::VoteNotInProgress);
+		/// Drop ballots that can no longer be voted on from `who`'s open ballots and return the
+		/// rest.
+		fn prune_open_ballots(who: &T::AccountId, vote_type: VoteType) -> Vec<(u64,VoteChoice,u128)> {
 			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > vote.end,Error::<T>::VoteStillInProgress);
+			let open = Self::get_open_ballots(who, vote_type)
+				.into_iter()
+				.filter(|(uid, _, _)| {
+					Self::get_vote((vote_type, *uid))
+						.map_or(false, |vote| vote.status == VoteStatus::InProgress && !vote.is_closed(now))
+				})
+				.collect::<Vec<_>>();
+			OpenBallots::<T>::insert(who, vote_type, &open);
+			open
+		}
 
-			match vote.yes_votes > vote.no_votes { 
-				true => {
-					vote.status = VoteStatus::Passed;
-					Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-					Self::deposit_event(Event::VoteEnded { vote_type : vote_type, uid: voting_id});
+		/// Track `who`'s vote on an open ballot along with the `delegated` weight it carried.
+		/// Only delegates and delegators are held to `MaxOpenBallots`. Anyone else keeps voting
+		/// past it, but can't delegate until the untracked ballots are over.
+		fn record_open_ballot(
+			who: &T::AccountId,
+			vote_type: VoteType,
+			voting_id: u64,
+			vote_cast: VoteChoice,
+			delegated: u128,
+		) -> DispatchResult {
+			let mut open = Self::prune_open_ballots(who, vote_type);
+			if (open.len() as u32) >= T::MaxOpenBallots::get() {
+				let delegating = Self::get_delegated_power(who, vote_type).delegators > 0 ||
+					Delegations::<T>::contains_key(who, vote_type);
+				ensure!(!delegating, Error::<T>::TooManyOpenBallots);
+
+				let end = Self::latest_end(vote_type, voting_id);
+				UntrackedBallotsEnd::<T>::mutate(who, vote_type, |e| *e = (*e).max(end));
+				return Ok(())
+			}
+			open.push((voting_id, vote_cast, delegated));
+			OpenBallots::<T>::insert(who, vote_type, open);
+			Ok(())
+		}
 
-					if vote_type == VoteType::ExpertVerification  {
-						let now = <frame_system::Pallet<T>>::block_number();
+		/// Latest block ballot `uid` can still be voted on, counting the late-vote extensions
+		/// it has left.
+		fn latest_end(vote_type: VoteType, uid: u64) -> T::BlockNumber {
+			let timing = Self::ballot_timing(vote_type);
+			let extensions_left = timing.max_extensions.saturating_sub(Self::get_ballot_extensions((vote_type,uid)));
+			Self::get_vote((vote_type,uid)).map_or_else(Zero::zero, |vote| {
+				vote.end + timing.extension.saturating_mul(extensions_left).into()
+			})
+		}
 
-						let end = now + T::VotingWindow::get().into();
+		/// Add or take back `weight` of delegated power on one of a delegate's open ballots,
+		/// never taking back more than was applied on it.
+		fn move_delegated_weight(vote_type: VoteType, ballot: &mut (u64,VoteChoice,u128), weight: u128, add: bool) {
+			let (uid, choice, applied) = ballot;
+			let weight = if add { weight } else { weight.min(*applied) };
+			*applied = if add { applied.saturating_add(weight) } else { *applied - weight };
+			Self::adjust_weight(vote_type, *uid, *choice, weight, add);
+		}
 
-						let new_vote = Vote::<T> {
-							yes_votes: 0,
-							no_votes: 0,
-							start: now,
-							end: end,
-							status: VoteStatus::InProgress,
-						};
+		fn adjust_weight(vote_type: VoteType, voting_id: u64, choice: VoteChoice, weight: u128, add: bool) {
+			Votes::<T>::mutate((vote_type, voting_id), |vote| {
+				if let Some(vote) = vote {
+					if add { vote.tally.add_weight(choice, weight) } else { vote.tally.remove_weight(choice, weight) }
+				}
+			});
+		}
 
-						let vote_type = VoteType::ExpertCouncilApproval;
+		/// One plus `who`'s decayed reputation, less their penalty points, and never below one.
+		pub fn reputation_weight(who: &T::AccountId, member: &Member<T>) -> u128 {
+			(1 + Self::reputation_of(who) as u128)
+				.saturating_sub(member.penalties as u128)
+				.max(1)
+		}
 
-						Votes::<T>::insert((vote_type.clone(),voting_id.clone()),new_vote);
+		/// Commitment to submit with `commit_vote` for a later `reveal_vote(vote_cast, salt)` by `who`.
+		pub fn vote_commitment(who: &T::AccountId, vote_cast: VoteChoice, salt: &[u8]) -> T::Hash {
+			T::Hashing::hash_of(&(who, vote_cast, salt))
+		}
 
-						Self::deposit_event(Event::NewVote { vote_type: vote_type,uid: voting_id});
+		/// Make a freshly opened ballot commit-reveal if its vote type is in that mode.
+		pub fn open_secret_ballot(vote_type: VoteType, uid: u64, end: T::BlockNumber) {
+			if Self::is_commit_reveal(vote_type) {
+				let ballot = SecretBallot::<T> {
+					reveal_end: end + T::RevealWindow::get().into(),
+					commits: 0,
+					reveals: 0,
+				};
+				SecretBallots::<T>::insert((vote_type,uid), ballot);
+			}
+		}
 
-					} else if vote_type == VoteType::ExpertCouncilApproval {
-						member.role = Roles::Expert;
-						Members::<T>::insert(who.clone(),&member);
-					} else if vote_type == VoteType::CuratorVerification  {
-						let now = <frame_system::Pallet<T>>::block_number();
+		/// Count the commitments left unrevealed on a ballot as abstentions and record them
+		/// against the members who made them.
+		pub fn close_secret_ballot(vote_type: VoteType, uid: u64) {
+			if !SecretBallots::<T>::contains_key((vote_type,uid)) {
+				return
+			}
+			let mut count: u32 = 0;
+			for (who, _) in VoteCommitments::<T>::drain_prefix((vote_type,uid)) {
+				MissedReveals::<T>::mutate(&who, |missed| *missed = missed.saturating_add(1));
+				count = count.saturating_add(1);
+			}
+			if count > 0 {
+				Self::deposit_event(Event::VotesUnrevealed { vote_type, uid, count });
+			}
+		}
 
-						let end = now + T::VotingWindow::get().into();
+		/// Fail with `Suspended` while `who` is under a suspension that has not lapsed.
+		pub fn ensure_not_suspended(who: &T::AccountId) -> DispatchResult {
+			ensure!(!Self::is_suspended(who), Error::<T>::Suspended);
+			Ok(())
+		}
 
-						let new_vote = Vote::<T> {
-							yes_votes: 0,
-							no_votes: 0,
-							start: now,
-							end: end,
-							status: VoteStatus::InProgress,
-						};
+		pub fn is_suspended(who: &T::AccountId) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			match Self::get_suspension(who) {
+				Some(suspension) => suspension.until.map_or(true, |until| now < until),
+				None => false,
+			}
+		}
 
-						let vote_type = VoteType::CuratorCouncilApproval;
+		/// Whether `role` is below `current` on the way up from Qualifier to Expert.
+		pub fn is_demotion(current: Roles, role: Roles) -> bool {
+			matches!(role, Roles::Qualifier | Roles::Contributor | Roles::Verifier) &&
+				matches!(current, Roles::Contributor | Roles::Verifier | Roles::Expert) &&
+				(role as u8) < (current as u8)
+		}
 
-						Votes::<T>::insert((vote_type.clone(),voting_id.clone()),new_vote);
+		/// Carry out the member action of a passed `VoteType::Proposal` ballot.
+		pub fn enact_member_action(uid: u64) -> DispatchResult {
+			let proposal = MemberProposals::<T>::take(uid).ok_or(Error::<T>::ProposalNotFound)?;
+			let who = proposal.target;
+			// The target may have resigned while the ballot was open.
+			let mut member = match Self::get_member(who.clone()) {
+				Some(member) => member,
+				None => return Ok(()),
+			};
 
-						Self::deposit_event(Event::NewVote { vote_type: vote_type,uid: voting_id});
-					} else if vote_type == VoteType::CuratorCouncilApproval {
-						member.role = Roles::Verifier;
-						Members::<T>::insert(who.clone(),&member);
+			match proposal.action {
+				MemberAction::Demote(role) => {
+					// Skip demotions the target's role no longer allows.
+					if !Self::is_demotion(member.role, role) {
+						return Ok(())
 					}
-
+					if member.role == Roles::Expert {
+						for domain in Self::domains_of(&who) {
+							ExpertDomains::<T>::remove(domain, &who);
+						}
+						Self::remove_expert(&who);
+					}
+					member.role = role;
+					Members::<T>::insert(&who,&member);
+					Self::deposit_event(Event::MemberDemoted { who, role });
+				},
+				MemberAction::Suspend(until) => Self::suspend(who, proposal.reason, until),
+				MemberAction::Remove => {
+					Self::remove_member(&who);
+					Banned::<T>::insert(&who, <frame_system::Pallet<T>>::block_number());
+					Self::deposit_event(Event::MemberRemoved { who });
 				},
-				false => {
-					vote.status = VoteStatus::Failed;
-					Votes::<T>::insert((vote_type.clone(),voting_id.clone()),vote);
-					Self::deposit_event(Event::VoteEnded { vote_type : vote_type, uid: voting_id});
-				}
 			}
 
 			Ok(())
 		}
 
-		#[pallet::call_index(7)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn set_membership(origin: OriginFor<T>, new_member: T::AccountId, member_role: u8, metadata: Vec<u8>) -> DispatchResult {
-			ensure_root(origin)?;
-			ensure!(!Members::<T>::contains_key(&new_member.clone()), Error::<T>::MemberAlreadyExists);
-
-			let role = match member_role {
-				1 => Roles::Qualifier,
-				2 => Roles::Contributor,
-				3 => Roles::Verifier,
-				4 => Roles::Expert,
-				5 => Roles::Collector,
-				_ => Roles::None,
+		pub fn suspend(who: T::AccountId, reason: Vec<u8>, until: Option<T::BlockNumber>) {
+			let suspension = Suspension::<T> {
+				reason: reason.clone(),
+				since: <frame_system::Pallet<T>>::block_number(),
+				until,
 			};
+			Suspensions::<T>::insert(&who, suspension);
+			Self::deposit_event(Event::MemberSuspended { who, until, reason });
+		}
 
-			let uid = Self::members_uid_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		/// Drop `who` from the DAO along with their delegations both ways, expertise and panel
+		/// seats. Votes they already cast stand, without the power delegated to them.
+		fn remove_member(who: &T::AccountId) {
+			for vote_type in [VoteType::Qualification, VoteType::Verification] {
+				if Delegations::<T>::contains_key(who, vote_type) {
+					let _ = Self::do_undelegate(who, vote_type);
+				}
+				for ballot in Self::prune_open_ballots(who, vote_type).iter_mut() {
+					let applied = ballot.2;
+					Self::move_delegated_weight(vote_type, ballot, applied, false);
+				}
+				for delegator in Delegators::<T>::take(who, vote_type) {
+					Delegations::<T>::remove(&delegator, vote_type);
+					Self::deposit_event(Event::Undelegated { who: delegator, vote_type });
+				}
+				DelegatedPowers::<T>::remove(who, vote_type);
+				OpenBallots::<T>::remove(who, vote_type);
+				UntrackedBallotsEnd::<T>::remove(who, vote_type);
+			}
+			for uid in PanelSeats::<T>::take(who) {
+				ExpertReviews::<T>::mutate(uid, |review| {
+					if let Some(review) = review {
+						review.panel.retain(|panelist| panelist != who);
+					}
+				});
+				for appeal_id in Self::get_upload_appeals(uid) {
+					Appeals::<T>::mutate(appeal_id, |appeal| match appeal {
+						Some(appeal) if appeal.status == VoteStatus::InProgress => appeal.panel.retain(|panelist| panelist != who),
+						_ => {},
+					});
+				}
+			}
+			// A lock still running stays until `unlock_voting_balance`, which needs no membership.
 			let now = <frame_system::Pallet<T>>::block_number();
+			if Self::get_voting_lock_expiry(who).map_or(false, |expiry| now > expiry) {
+				T::Currency::remove_lock(VOTING_LOCK_ID, who);
+				VotingLockExpiry::<T>::remove(who);
+			}
+			for domain in Self::domains_of(who) {
+				ExpertDomains::<T>::remove(domain, who);
+			}
+			Self::remove_expert(who);
+			Members::<T>::remove(who);
+			Suspensions::<T>::remove(who);
+			Reputations::<T>::remove(who);
+		}
 
-			let member = Member::<T> {
-				member_id: uid.clone(),
-				metadata: metadata,
-				vote_count: 0,
-				approved_contributions: 0,
-				role: role,
-				joined: now,
-			};
+		/// Whether enough Experts turned out on member-action ballot `vote`.
+		fn has_member_action_quorum(vote: &Vote<T>) -> bool {
+			let experts = Self::get_experts().len() as u128;
+			vote.tally.turnout() >= T::MemberActionQuorum::get().mul_ceil(experts)
+		}
 
-			Members::<T>::insert(new_member.clone(),&member);
-			MembersCount::<T>::put(uid.clone());
+		/// Return the deposit of amendment `uid`, or slash it if the amendment failed.
+		pub fn release_amendment_deposit(uid: u64, passed: bool) {
+			if let Some((who, amount)) = AmendmentDeposits::<T>::take(uid) {
+				if passed {
+					T::Currency::unreserve(&who, amount);
+				} else {
+					let _ = T::Currency::slash_reserved(&who, amount);
+					Self::deposit_event(Event::AmendmentDepositSlashed { uid, who, amount });
+				}
+			}
+		}
 
-			Self::deposit_event(Event::MemberAdded { who: new_member, uid });
+		/// Return the deposit of member action `uid`, or slash it if the ballot failed.
+		pub fn release_member_action_deposit(uid: u64, passed: bool) {
+			if let Some((who, amount)) = MemberActionDeposits::<T>::take(uid) {
+				if passed {
+					T::Currency::unreserve(&who, amount);
+				} else {
+					let _ = T::Currency::slash_reserved(&who, amount);
+					Self::deposit_event(Event::MemberActionDepositSlashed { uid, who, amount });
+				}
+			}
+		}
+
+		/// Return the deposit of takedown `uid`, or slash it if the Experts vetoed it.
+		pub fn release_takedown_deposit(uid: u64, vetoed: bool) {
+			if let Some((who, amount)) = TakedownDeposits::<T>::take(uid) {
+				if vetoed {
+					let _ = T::Currency::slash_reserved(&who, amount);
+					Self::deposit_event(Event::TakedownDepositSlashed { uid, who, amount });
+				} else {
+					T::Currency::unreserve(&who, amount);
+				}
+			}
+		}
+
+		/// Seat each of `panel` on upload `uid`'s review or appeal panel.
+		fn take_seats(uid: u64, panel: &[T::AccountId]) {
+			for who in panel {
+				PanelSeats::<T>::append(who, uid);
+			}
+		}
+
+		/// Free the seats of `panel` once upload `uid`'s review or appeal is decided.
+		pub fn release_seats(uid: u64, panel: &[T::AccountId]) {
+			for who in panel {
+				PanelSeats::<T>::mutate(who, |seats| {
+					if let Some(pos) = seats.iter().position(|seat| *seat == uid) {
+						seats.swap_remove(pos);
+					}
+				});
+			}
+		}
 
+		/// Check that `member` holds the role required to vote on a `vote_type` ballot.
+		pub fn ensure_eligible_to_vote(member: &Member<T>, vote_type: VoteType) -> DispatchResult {
+			match vote_type {
+				VoteType::Verification | VoteType::ExpertVerification | VoteType::CuratorVerification | VoteType::CollectorVerification |
+				VoteType::Amendment | VoteType::CollectionReview =>
+					ensure!(member.role == Roles::Verifier, Error::<T>::NotEligibleToVerify),
+				VoteType::ExpertCouncilApproval | VoteType::CuratorCouncilApproval | VoteType::CollectorCouncilApproval |
+				VoteType::Objection | VoteType::Appeal | VoteType::Takedown | VoteType::Proposal =>
+					ensure!(member.role == Roles::Expert, Error::<T>::NotAnExpert),
+				VoteType::Qualification => {},
+			}
 			Ok(())
 		}
 
-		#[pallet::call_index(8)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn raise_expert_objection(origin: OriginFor<T>, upload_id: u64, reason: Vec<u8>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			// Check if member
-			let mut member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			ensure!(member.role == Roles::Expert,Error::<T>::NotAnExpert);
+		/// Fetch every upload in Qualification that has no attestation yet from the content
+		/// gateway and submit the outcome as a signed unsigned transaction.
+		pub fn attest_pending_uploads(now: T::BlockNumber) -> Result<(), &'static str> {
+			let gateway = Self::get_content_gateway();
+			let signer = Signer::<T, T::AttesterId>::any_account();
+			if gateway.is_empty() || !signer.can_sign() {
+				return Ok(())
+			}
 
-			let upload = Self::get_upload(upload_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
+			for uid in PendingAttestations::<T>::iter_keys() {
+				let upload = match Self::get_upload(uid) {
+					Some(upload) if upload.status == UploadStatus::QualificationVoteInProgress => upload,
+					_ => continue,
+				};
+
+				// Don't hammer the gateway while a previous attestation waits for inclusion.
+				let key = [b"bhdao::attest::".as_slice(), &uid.encode()].concat();
+				let last_attempt = StorageValueRef::persistent(&key);
+				if let Ok(Some(last)) = last_attempt.get::<T::BlockNumber>() {
+					if now < last + ATTESTATION_RETRY_BLOCKS.into() {
+						continue
+					}
+				}
+				last_attempt.set(&now);
+
+				// The gateway itself could not be reached, try again later.
+				let (available, intact) = match Self::check_content(&gateway, &upload.hash) {
+					Some(result) => result,
+					None => continue,
+				};
+
+				Self::submit_check(&signer, uid, available, intact, |check, signature| {
+					Call::submit_content_attestation { check, signature }
+				})
+				.map_err(|_| "Failed to submit content attestation")?;
+			}
 
-			ensure!(upload.status == UploadStatus::UnderExpertReview,Error::<T>::NotUnderExpertReview);
+			Ok(())
+		}
 
-			let objection = Objection::<T> {
-				objector: who.clone(),
-				hash: reason,
-			};
+		/// Fetch the content proposed by every pending amendment that has no attestation yet and
+		/// submit the outcome, keyed by the amended upload.
+		pub fn attest_pending_amendments(now: T::BlockNumber) -> Result<(), &'static str> {
+			let gateway = Self::get_content_gateway();
+			let signer = Signer::<T, T::AttesterId>::any_account();
+			if gateway.is_empty() || !signer.can_sign() {
+				return Ok(())
+			}
 
-			let mut expert_review = Self::get_expert_review(upload_id.clone()).ok_or(Error::<T>::NotUnderExpertReview)?;
+			for (upload_id, uid) in PendingAmendments::<T>::iter() {
+				let amendment = match Self::get_amendment(uid) {
+					Some(amendment) if !AmendmentAttestations::<T>::contains_key(uid) => amendment,
+					_ => continue,
+				};
+
+				let key = [b"bhdao::attest::amendment::".as_slice(), &uid.encode()].concat();
+				let last_attempt = StorageValueRef::persistent(&key);
+				if let Ok(Some(last)) = last_attempt.get::<T::BlockNumber>() {
+					if now < last + ATTESTATION_RETRY_BLOCKS.into() {
+						continue
+					}
+				}
+				last_attempt.set(&now);
 
-			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > expert_review.start && now < expert_review.end, Error::<T>::VotingWindowNotValid);
+				let (available, intact) = match Self::check_content(&gateway, &amendment.hash) {
+					Some(result) => result,
+					None => continue,
+				};
 
-			let mut object1 = expert_review.objections.clone();
+				Self::submit_check(&signer, upload_id, available, intact, |check, signature| {
+					Call::submit_amendment_attestation { check, signature }
+				})
+				.map_err(|_| "Failed to submit amendment attestation")?;
+			}
 
-			match object1 {
-				Some(mut object1) => {
-					object1.push(objection);
-					expert_review.objections = Some(object1);
-				} ,
-				None => {
-					let mut obj1 = Vec::new();
-					obj1.push(objection);
-					expert_review.objections = Some(obj1);
+			Ok(())
+		}
+
+		/// Re-check a sample of `AuditSampleSize` Verified uploads each audit epoch, starting from
+		/// a random upload id, and submit the results as unsigned transactions. A run scans at most
+		/// `AUDIT_SCAN_LIMIT` ids and the next one carries on from there.
+		pub fn audit_verified_uploads(now: T::BlockNumber) -> Result<(), &'static str> {
+			let gateway = Self::get_content_gateway();
+			let signer = Signer::<T, T::AttesterId>::any_account();
+			let count = UploadCount::<T>::get();
+			if gateway.is_empty() || !signer.can_sign() || count == 0 {
+				return Ok(())
+			}
+
+			// The epoch last worked on, the next upload id to scan, the audits submitted and the
+			// ids scanned in that epoch.
+			let epoch = Self::audit_epoch(now);
+			let progress = StorageValueRef::persistent(b"bhdao::audit::progress");
+			let (mut next, mut submitted, mut scanned) = match progress.get::<(T::BlockNumber, u64, u32, u64)>() {
+				Ok(Some((last_epoch, next, submitted, scanned))) if last_epoch == epoch => (next, submitted, scanned),
+				_ => {
+					let seed = sp_io::offchain::random_seed();
+					(u64::decode(&mut &seed[..]).unwrap_or_default() % count + 1, 0, 0)
 				},
+			};
+
+			let limit = scanned.saturating_add(AUDIT_SCAN_LIMIT).min(count);
+			while scanned < limit && submitted < T::AuditSampleSize::get() {
+				let uid = next;
+				next = if next >= count { 1 } else { next + 1 };
+				scanned += 1;
+
+				let upload = match Self::get_upload(uid) {
+					Some(upload) if upload.status == UploadStatus::Verified && !Self::audited_this_epoch(uid, now) => upload,
+					_ => continue,
+				};
+				let (available, intact) = match Self::check_content(&gateway, &upload.hash) {
+					Some(result) => result,
+					None => continue,
+				};
+
+				Self::submit_check(&signer, uid, available, intact, |check, signature| {
+					Call::submit_content_audit { check, signature }
+				})
+				.map_err(|_| "Failed to submit content audit")?;
+				submitted += 1;
 			}
 
-			ExpertReviews::<T>::insert(upload_id.clone(),expert_review);
-			Self::deposit_event(Event::ObjectionRaised {  uid: upload_id, who:  who});
+			progress.set(&(epoch, next, submitted, scanned));
+			Ok(())
+		}
 
+		/// Sign the outcome of a gateway check with the local attester key and submit it.
+		fn submit_check(
+			signer: &Signer<T, T::AttesterId, frame_system::offchain::ForAny>,
+			upload_id: u64,
+			available: bool,
+			intact: bool,
+			call: impl Fn(ContentCheck<T::Public>, T::Signature) -> Call<T>,
+		) -> Result<(), ()> {
+			match signer.send_unsigned_transaction(
+				|account| ContentCheck { upload_id, available, intact, public: account.public.clone() },
+				call,
+			) {
+				Some((_, result)) => result,
+				None => Err(()),
+			}
+		}
+
+		/// Check that `check` is signed by a registered attester.
+		fn ensure_attester(check: &ContentCheck<T::Public>, signature: &T::Signature) -> Result<(), TransactionValidityError> {
+			if !SignedPayload::<T>::verify::<T::AttesterId>(check, signature.clone()) {
+				return Err(InvalidTransaction::BadProof.into())
+			}
+			if !Self::get_attesters().contains(&check.public.clone().into_account()) {
+				return Err(InvalidTransaction::BadSigner.into())
+			}
 			Ok(())
 		}
 
-		#[pallet::call_index(9)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn finalize_expert_review(origin: OriginFor<T>, upload_id: u64) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			// Check if member
-			let member = Self::get_member(who.clone()).ok_or(Error::<T>::NotAMember)?;
-			// Check if the vote exists
-			let mut expert_review = Self::get_expert_review(upload_id.clone()).ok_or(Error::<T>::NotUnderExpertReview)?;
-			let mut upload = Self::get_upload(upload_id.clone()).ok_or(Error::<T>::UploadNotFound)?;
-			
+		fn ensure_attestable(check: &ContentCheck<T::Public>, signature: &T::Signature) -> Result<(), TransactionValidityError> {
+			Self::ensure_attester(check, signature)?;
+			let upload = Self::get_upload(check.upload_id).ok_or(InvalidTransaction::Stale)?;
+			if upload.status != UploadStatus::QualificationVoteInProgress ||
+				ContentAttestations::<T>::contains_key(check.upload_id)
+			{
+				return Err(InvalidTransaction::Stale.into())
+			}
+			Ok(())
+		}
+
+		fn ensure_amendment_attestable(check: &ContentCheck<T::Public>, signature: &T::Signature) -> Result<(), TransactionValidityError> {
+			Self::ensure_attester(check, signature)?;
+			let uid = Self::get_pending_amendment(check.upload_id).ok_or(InvalidTransaction::Stale)?;
+			if AmendmentAttestations::<T>::contains_key(uid) {
+				return Err(InvalidTransaction::Stale.into())
+			}
+			Ok(())
+		}
+
+		fn ensure_auditable(check: &ContentCheck<T::Public>, signature: &T::Signature) -> Result<(), TransactionValidityError> {
+			Self::ensure_attester(check, signature)?;
+			let upload = Self::get_upload(check.upload_id).ok_or(InvalidTransaction::Stale)?;
 			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now > expert_review.end,Error::<T>::VoteStillInProgress);
+			if upload.status != UploadStatus::Verified || Self::audited_this_epoch(check.upload_id, now) {
+				return Err(InvalidTransaction::Stale.into())
+			}
+			Ok(())
+		}
 
-			let object1 = expert_review.objections;
+		pub fn audit_epoch(now: T::BlockNumber) -> T::BlockNumber {
+			now / T::AuditPeriod::get().max(1).into()
+		}
 
-			match object1 {
-				Some(object1) => {
-					upload.status = UploadStatus::Rejected;
-					Uploads::<T>::insert(upload_id.clone(),&upload);
-				} ,
-				None => {
-					upload.status = UploadStatus::Verified;
-					Uploads::<T>::insert(upload_id.clone(),&upload);
-					// Move to finalize_expert_review
-					let exist = Approved::<T>::exists();
-					let token_id = Self::token_uid_count().checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
-					let tuid: TokenIdOf<T> = token_id.try_into().ok().unwrap();
-					let share1 = Self::get_contributor_share();
-
-					pallet_nft::Pallet::<T>::mint(origin.clone(),upload.creator,tuid,share1,upload.hash).ok();
-
-					match exist {
-						true => {
-							let mut temp = Approved::<T>::get();
-							temp.push(tuid);
-							Approved::<T>::put(temp);
-						},
-						false => {
-							let mut temp: Vec<TokenIdOf<T>> = Vec::new();
-							temp.push(tuid);
-							Approved::<T>::put(temp);
-						}
-					};
-		
+		pub fn audited_this_epoch(upload_id: u64, now: T::BlockNumber) -> bool {
+			Self::get_content_audit(upload_id)
+				.map_or(false, |audit| Self::audit_epoch(audit.last_audited) == Self::audit_epoch(now))
+		}
+
+		/// Fetch `cid` from the gateway and return whether it is available and matches its
+		/// digest. `None` means the gateway itself could not be reached.
+		pub fn check_content(gateway: &[u8], cid: &[u8]) -> Option<(bool, bool)> {
+			match Self::fetch_content(gateway, cid) {
+				Ok(Some(content)) => {
+					let intact = crate::cid::sha2_256_digest(cid)
+						.map_or(false, |digest| digest == sp_io::hashing::sha2_256(&content));
+					Some((true, intact))
 				},
+				Ok(None) => Some((false, false)),
+				Err(_) => None,
+			}
+		}
+
+		/// Fetch the raw block for `cid` from the gateway. Returns `Ok(None)` if the gateway
+		/// doesn't have the content or it can't be a valid upload, and an error on a transport
+		/// failure, a server error or rate limiting.
+		pub fn fetch_content(gateway: &[u8], cid: &[u8]) -> Result<Option<Vec<u8>>, http::Error> {
+			let gateway = match gateway.last() {
+				Some(b'/') => &gateway[..gateway.len() - 1],
+				_ => gateway,
+			};
+			let url = [gateway, b"/ipfs/", cid, b"?format=raw"].concat();
+			let url = match sp_std::str::from_utf8(&url) {
+				Ok(url) => url,
+				Err(_) => return Ok(None),
 			};
 
-			Ok(())
-		}
+			let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+			let pending = http::Request::get(url)
+				.deadline(deadline)
+				.send()
+				.map_err(|_| http::Error::IoError)?;
+			let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+
+			match response.code {
+				200 => {},
+				// Not found, gone, or a CID the gateway can't parse.
+				400..=499 if response.code != 429 => return Ok(None),
+				// Server errors and rate limiting say nothing about the content, only the gateway.
+				_ => return Err(http::Error::Unknown),
+			}
 
-		
+			let content = response.body().take(MAX_CONTENT_SIZE + 1).collect::<Vec<u8>>();
+			if content.len() > MAX_CONTENT_SIZE {
+				return Ok(None)
+			}
+
+			Ok(Some(content))
+		}
 	}
 
-	// Helpful functions
-	impl<T: Config> Pallet<T> {
-		
+	/// Only Collectors can acquire an upload's NFT during its early access window.
+	impl<T: Config> pallet_nft::TransferFilter<T::AccountId, TokenIdOf<T>> for Pallet<T> {
+		fn can_receive(to: &T::AccountId, id: TokenIdOf<T>) -> bool {
+			Self::has_early_access(to, id)
+		}
 	}
 }
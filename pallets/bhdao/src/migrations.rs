@@ -0,0 +1,388 @@
+//! Storage migrations, one module per storage version. Each one only runs on a chain at the
+//! version right before it, so the whole list can stay in the runtime's `Executive`.
+
+use crate::{Config, Pallet, VoteStatus, VoteType};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Put `version` and return the weight of a migration that read and wrote `items` entries.
+fn finish<T: Config>(version: u16, items: u64) -> Weight {
+	StorageVersion::new(version).put::<Pallet<T>>();
+	log::info!(target: "runtime::bhdao", "Migrated {} entries to storage version {}", items, version);
+	T::DbWeight::get().reads_writes(items + 1, items + 1)
+}
+
+/// Whether the pallet's storage is at `version`, logging a skipped migration if not.
+fn at_version<T: Config>(version: u16) -> bool {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	if on_chain != version {
+		log::info!(target: "runtime::bhdao", "Skipping migration from {:?}, storage is at {:?}", version, on_chain);
+	}
+	on_chain == version
+}
+
+/// Votes gain weighted tallies next to their head counts.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldVote<BlockNumber> {
+		pub yes_votes: u64,
+		pub no_votes: u64,
+		pub start: BlockNumber,
+		pub end: BlockNumber,
+		pub status: VoteStatus,
+	}
+
+	/// A vote as of storage version 1.
+	#[derive(Encode, Decode)]
+	pub struct Vote<BlockNumber> {
+		pub yes_votes: u64,
+		pub no_votes: u64,
+		pub yes_weight: u128,
+		pub no_weight: u128,
+		pub start: BlockNumber,
+		pub end: BlockNumber,
+		pub status: VoteStatus,
+	}
+
+	#[storage_alias]
+	pub type Votes<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(VoteType, u64),
+		Vote<<T as frame_system::Config>::BlockNumber>,
+	>;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(0) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Every vote so far was one member one vote.
+			let mut items = 0;
+			Votes::<T>::translate::<OldVote<T::BlockNumber>, _>(|_, old| {
+				items += 1;
+				Some(Vote {
+					yes_votes: old.yes_votes,
+					no_votes: old.no_votes,
+					yes_weight: old.yes_votes.into(),
+					no_weight: old.no_votes.into(),
+					start: old.start,
+					end: old.end,
+					status: old.status,
+				})
+			});
+
+			finish::<T>(1, items)
+		}
+	}
+}
+
+/// Index the members in the Expert role in `Experts`.
+pub mod v2 {
+	use super::*;
+	use crate::{Experts, Roles};
+
+	/// A member as of storage version 1.
+	#[derive(Encode, Decode)]
+	pub struct Member<BlockNumber> {
+		pub member_id: u32,
+		pub metadata: Vec<u8>,
+		pub vote_count: u64,
+		pub approved_contributions: u32,
+		pub role: Roles,
+		pub joined: BlockNumber,
+	}
+
+	#[storage_alias]
+	pub type Members<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Member<<T as frame_system::Config>::BlockNumber>,
+	>;
+
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(1) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut items = 0;
+			let experts = Members::<T>::iter()
+				.inspect(|_| items += 1)
+				.filter(|(_, member)| member.role == Roles::Expert)
+				.map(|(who, _)| who)
+				.collect::<Vec<_>>();
+			if experts.len() as u32 > T::MaxExperts::get() {
+				log::warn!(target: "runtime::bhdao", "{} Experts exceed MaxExperts", experts.len());
+			}
+			Experts::<T>::put(experts);
+
+			finish::<T>(2, items)
+		}
+	}
+}
+
+/// Expert reviews gain their panel and the panelists who responded, members their penalty
+/// points.
+pub mod v3 {
+	use super::*;
+	use crate::{ExpertReview, ExpertReviews, Member, Members, Objection};
+
+	#[derive(Encode, Decode)]
+	pub struct OldExpertReview<T: Config> {
+		pub start: T::BlockNumber,
+		pub end: T::BlockNumber,
+		pub objections: Option<Vec<Objection<T>>>,
+	}
+
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(2) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Reviews under way have no panel. They get one when they are finalized.
+			let mut items = 0;
+			ExpertReviews::<T>::translate::<OldExpertReview<T>, _>(|_, old| {
+				items += 1;
+				Some(ExpertReview::<T> {
+					start: old.start,
+					end: old.end,
+					objections: old.objections,
+					panel: Vec::new(),
+					responded: Vec::new(),
+				})
+			});
+			Members::<T>::translate::<v2::Member<T::BlockNumber>, _>(|_, old| {
+				items += 1;
+				Some(Member::<T> {
+					member_id: old.member_id,
+					metadata: old.metadata,
+					vote_count: old.vote_count,
+					approved_contributions: old.approved_contributions,
+					role: old.role,
+					joined: old.joined,
+					penalties: 0,
+				})
+			});
+
+			finish::<T>(3, items)
+		}
+	}
+}
+
+/// Uploads gain subject categories.
+pub mod v4 {
+	use super::*;
+	use crate::{Upload, UploadStatus, Uploads};
+
+	#[derive(Encode, Decode)]
+	pub struct OldUpload<AccountId> {
+		pub creator: AccountId,
+		pub hash: Vec<u8>,
+		pub status: UploadStatus,
+	}
+
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(3) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut items = 0;
+			Uploads::<T>::translate::<OldUpload<T::AccountId>, _>(|_, old| {
+				items += 1;
+				Some(Upload::<T> { creator: old.creator, hash: old.hash, status: old.status, categories: Vec::new() })
+			});
+
+			finish::<T>(4, items)
+		}
+	}
+}
+
+/// Index delegators by delegate and panel seats by Expert, so members can be removed without
+/// leaving delegations or seats behind.
+pub mod v5 {
+	use super::*;
+	use crate::{Appeals, Delegations, Delegators, ExpertReviews, PanelSeats, UploadStatus, Uploads};
+
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(4) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut items = 0;
+			for (delegator, vote_type, delegation) in Delegations::<T>::iter() {
+				items += 1;
+				Delegators::<T>::append(&delegation.delegate, vote_type, delegator);
+			}
+			for (uid, review) in ExpertReviews::<T>::iter() {
+				items += 1;
+				let under_review = Uploads::<T>::get(uid).map_or(false, |upload| upload.status == UploadStatus::UnderExpertReview);
+				if under_review {
+					for who in review.panel {
+						PanelSeats::<T>::append(who, uid);
+					}
+				}
+			}
+			for (_, appeal) in Appeals::<T>::iter() {
+				items += 1;
+				if appeal.status == VoteStatus::InProgress {
+					for who in appeal.panel {
+						PanelSeats::<T>::append(who, appeal.upload_id);
+					}
+				}
+			}
+
+			finish::<T>(5, items)
+		}
+	}
+}
+
+/// Votes are kept as ballots of the shared ballot engine. A ballot encodes the same as a vote
+/// of storage version 1, so only the version moves.
+pub mod v6 {
+	use super::*;
+
+	pub struct MigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(5) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			finish::<T>(6, 0)
+		}
+	}
+}
+
+/// Ballots gain abstentions and vetoes in their tally, and the votes kept on open ballots record
+/// the choice made instead of a yes or no. Open ballots also record the delegated weight applied
+/// on them.
+pub mod v7 {
+	use super::*;
+	use crate::{Ballot, BallotVotes, OpenBallots, Tally, VoteChoice, Votes};
+
+	pub struct MigrateToV7<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(6) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut items = 0;
+			Votes::<T>::translate::<v1::Vote<T::BlockNumber>, _>(|_, old| {
+				items += 1;
+				let tally = Tally {
+					yes_votes: old.yes_votes,
+					no_votes: old.no_votes,
+					yes_weight: old.yes_weight,
+					no_weight: old.no_weight,
+					..Default::default()
+				};
+				Some(Ballot { tally, start: old.start, end: old.end, status: old.status })
+			});
+			BallotVotes::<T>::translate::<bool, _>(|_, _, aye| {
+				items += 1;
+				Some(VoteChoice::from(aye))
+			});
+			// The weight applied on each ballot wasn't recorded. What the member's delegators
+			// lend them now is the best estimate of it.
+			OpenBallots::<T>::translate::<Vec<(u64, bool)>, _>(|who, vote_type, old| {
+				items += 1;
+				Some(old
+					.into_iter()
+					.map(|(uid, aye)| (uid, VoteChoice::from(aye), Pallet::<T>::delegated_weight(&who, vote_type, uid)))
+					.collect())
+			});
+
+			finish::<T>(7, items)
+		}
+	}
+}
+
+/// Index amendments by upload, so a redaction can clear every amendment of the upload.
+pub mod v8 {
+	use super::*;
+	use crate::{Amendments, UploadAmendments};
+
+	pub struct MigrateToV8<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(7) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut items = 0;
+			for (uid, amendment) in Amendments::<T>::iter() {
+				items += 1;
+				UploadAmendments::<T>::append(amendment.upload_id, uid);
+			}
+
+			finish::<T>(8, items)
+		}
+	}
+}
+
+/// Takedowns record whether they froze the upload's token.
+pub mod v9 {
+	use super::*;
+	use crate::{Takedown, Takedowns};
+
+	#[derive(Encode, Decode)]
+	pub struct OldTakedown<AccountId, Hash> {
+		pub upload_id: u64,
+		pub requester: AccountId,
+		pub reason: Hash,
+		pub status: VoteStatus,
+	}
+
+	pub struct MigrateToV9<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if !at_version::<T>(8) {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Takedowns under way used to be thawed when rejected whoever froze the token, so
+			// they keep doing so.
+			let mut items = 0;
+			Takedowns::<T>::translate::<OldTakedown<T::AccountId, T::Hash>, _>(|_, old| {
+				items += 1;
+				Some(Takedown::<T> {
+					upload_id: old.upload_id,
+					requester: old.requester,
+					reason: old.reason,
+					status: old.status,
+					froze_token: old.status == VoteStatus::InProgress,
+				})
+			});
+
+			finish::<T>(9, items)
+		}
+	}
+}
@@ -1,9 +1,10 @@
 use crate as pallet_bhdao;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64,OnFinalize, OnInitialize};
+use frame_support::{parameter_types, traits::{ConstU128, ConstU16, ConstU32, ConstU64, GenesisBuild, OnFinalize, OnIdle, OnInitialize, Randomness}, weights::Weight};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -17,6 +18,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		BhdaoModule: pallet_bhdao,
 		NftModule: pallet_nft,
 	}
@@ -40,7 +42,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -49,23 +51,140 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 pub const VOTING_WINDOW: u32 = 1000;
+pub const AUDIT_PERIOD: u32 = 100;
+pub const REVEAL_WINDOW: u32 = 200;
+pub const OBJECTION_DEPOSIT: u128 = 100;
+pub const APPEAL_DEPOSIT: u128 = 500;
+pub const APPEAL_PERIOD: u32 = 500;
+pub const COLLECTOR_EARLY_ACCESS: u32 = 300;
+pub const CONTRIBUTOR_THRESHOLD: u32 = 4;
+pub const REPUTATION_DECAY_PERIOD: u32 = 10_000;
+pub const APPLICATION_DEPOSIT: u128 = 50;
+pub const MEMBER_ACTION_DEPOSIT: u128 = 200;
+pub const AMENDMENT_DEPOSIT: u128 = 50;
+pub const TAKEDOWN_COOLDOWN: u32 = 500;
+pub const TAKEDOWN_DEPOSIT: u128 = 50;
+pub const MAX_SETTLEMENTS: u32 = 4;
+
+parameter_types! {
+	pub const ReputationDecay: Perbill = Perbill::from_percent(50);
+	pub const VetoThreshold: Perbill = Perbill::from_percent(33);
+	pub const MemberActionQuorum: Perbill = Perbill::from_percent(50);
+	pub const MinProposalStake: Perbill = Perbill::from_percent(10);
+}
+
+/// Deterministic stand-in for the runtime's randomness source.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
+/// Account whose offchain worker key signs content checks in the tests.
+pub const ATTESTER: u64 = 99;
+
+pub struct TestAttesterId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAttesterId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
 
 impl pallet_bhdao::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type AttesterId = TestAttesterId;
 	type VotingWindow = ConstU32<VOTING_WINDOW>;
+	type UnsignedPriority = ConstU64<{ u64::MAX / 2 }>;
+	type AuditPeriod = ConstU32<AUDIT_PERIOD>;
+	type AuditSampleSize = ConstU32<5>;
+	type MaxAuditFailures = ConstU32<3>;
+	type MaxOpenBallots = ConstU32<16>;
+	type MaxDelegators = ConstU32<8>;
+	type RevealWindow = ConstU32<REVEAL_WINDOW>;
+	type ObjectionDeposit = ConstU128<OBJECTION_DEPOSIT>;
+	type MaxObjectionEvidence = ConstU32<8>;
+	type Randomness = TestRandomness;
+	type AppealDeposit = ConstU128<APPEAL_DEPOSIT>;
+	type AppealPeriod = ConstU32<APPEAL_PERIOD>;
+	type AppealPanelSize = ConstU32<3>;
+	type ReviewPanelSize = ConstU32<3>;
+	type MaxExperts = ConstU32<16>;
+	type MaxCategories = ConstU32<4>;
+	type MaxCollectionSize = ConstU32<4>;
+	type MaxCoContributors = ConstU32<3>;
+	type MaxSubjects = ConstU32<3>;
+	type MinKycAttestations = ConstU32<2>;
+	type CollectorEarlyAccess = ConstU32<COLLECTOR_EARLY_ACCESS>;
+	type AccurateVoteReward = ConstU32<1>;
+	type MaxSettlements = ConstU32<MAX_SETTLEMENTS>;
+	type VerifiedUploadReward = ConstU32<5>;
+	type RejectedUploadPenalty = ConstU32<2>;
+	type UpheldObjectionPenalty = ConstU32<2>;
+	type ContributorThreshold = ConstU32<CONTRIBUTOR_THRESHOLD>;
+	type ReputationDecayPeriod = ConstU32<REPUTATION_DECAY_PERIOD>;
+	type ReputationDecay = ReputationDecay;
+	type VetoThreshold = VetoThreshold;
+	type ApplicationDeposit = ConstU128<APPLICATION_DEPOSIT>;
+	type MemberActionDeposit = ConstU128<MEMBER_ACTION_DEPOSIT>;
+	type MemberActionQuorum = MemberActionQuorum;
+	type AmendmentDeposit = ConstU128<AMENDMENT_DEPOSIT>;
+	type MaxAmendments = ConstU32<4>;
+	type TakedownCooldown = ConstU32<TAKEDOWN_COOLDOWN>;
+	type TakedownDeposit = ConstU128<TAKEDOWN_DEPOSIT>;
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
 }
 
 pub type Balance = u128;
 
+pub const INITIAL_BALANCE: Balance = 10_000;
+
 impl pallet_nft::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type TokenId = u128;
 	type Balance = Balance;
+	type MintOrigin = frame_system::EnsureRoot<u64>;
+	type FreezeOrigin = frame_system::EnsureRoot<u64>;
+	type ProposalPeriod = ConstU32<10>;
+	type MaxOpenProposals = ConstU32<2>;
+	type MinProposalStake = MinProposalStake;
+	type MaxProposalCleanup = ConstU32<2>;
+	type TransferFilter = BhdaoModule;
 }
 
 pub fn run_to_block(n: u64) {
 	while System::block_number() < n {
+		BhdaoModule::on_idle(System::block_number(), Weight::MAX);
 		BhdaoModule::on_finalize(System::block_number());
 		System::set_block_number(System::block_number() + 1);
 		BhdaoModule::on_initialize(System::block_number());
@@ -74,5 +193,10 @@ pub fn run_to_block(n: u64) {
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	UintAuthorityId::set_all_keys(vec![ATTESTER]);
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (1..=10).map(|who| (who, INITIAL_BALANCE)).collect() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
 }
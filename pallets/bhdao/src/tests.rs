@@ -1,5 +1,20 @@
-use crate::{mock::*, Error, Event, VoteType, VoteStatus, Vote, Roles, Upload, UploadStatus};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, Event, VoteType, VoteStatus, Ballot, Tally, VoteChoice, Roles, Upload, UploadStatus, ContentAttestation, ContentCheck, VoteWeighting, Conviction, DelegatedPower, ExpertReview, ObjectionCategory, ObjectionStatus, Rejection, MemberAction, CollectionStatus, RoleRequirement, UnmetRequirement, BallotTiming, License};
+use pallet_bhdao_ballots::{BallotError, BallotHandler};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::{Currency, GenesisBuild}};
+use sp_core::{
+	offchain::{
+		testing, Externalities, HttpError, HttpRequestId, HttpRequestStatus, OffchainDbExt, OffchainWorkerExt,
+		OpaqueNetworkState, OpaquePeerId, Timestamp, TransactionPoolExt,
+	},
+	H256,
+};
+use std::sync::{atomic::{AtomicU16, Ordering}, Arc};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+};
 
 #[test]
 fn it_works_for_join_dao() {
@@ -60,10 +75,10 @@ fn it_uploads_a_document() {
 
 		// Contributor uploads a document
 
-		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(2),b"Doc1".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(2),b"Doc1".to_vec(),vec![],None));
 		assert_eq!(BhdaoModule::upload_uid_count(),1u64);
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Vote{yes_votes: 0, no_votes: 0,start: 1, end:1001,status: VoteStatus::InProgress }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Ballot{tally: Tally::default(),start: 1, end:1001,status: VoteStatus::InProgress }));
 
 	});
 }
@@ -88,32 +103,32 @@ fn it_votes_on_document() {
 
 		// Contributor uploads a document
 
-		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
 		assert_eq!(BhdaoModule::upload_uid_count(),1u64);
 
 		// Check if Vote exists
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Vote{yes_votes: 0, no_votes: 0,start: 1, end:1001,status: VoteStatus::InProgress }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Ballot{tally: Tally::default(),start: 1, end:1001,status: VoteStatus::InProgress }));
 
 		// Member 2 casts vote at block 200
 
 		run_to_block(200);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,true));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye));
 
 		// Member 3 casts vote at block 500
 
 		run_to_block(500);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,true));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,VoteChoice::Aye));
 
 		// Member 4 casts vote at block 900
 
 		run_to_block(900);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Qualification,1,false));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Qualification,1,VoteChoice::Nay));
 
 		// Member 5 tries to cast vote after the time has expired
 
 		run_to_block(1100);
-		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(5),VoteType::Qualification,1,false),Error::<Test>::VotingWindowNotValid);
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(5),VoteType::Qualification,1,VoteChoice::Nay),Error::<Test>::VotingWindowNotValid);
 
 		// Finalize vote
 
@@ -122,11 +137,11 @@ fn it_votes_on_document() {
 
 		// Check if Vote passed
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Vote{yes_votes: 2, no_votes: 1,start: 1, end:1001,status: VoteStatus::Passed }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Ballot{tally: Tally{yes_votes: 2, no_votes: 1, yes_weight: 2, no_weight: 1, ..Default::default()},start: 1, end:1001,status: VoteStatus::Passed }));
 
 		// Check if Verification voting started 
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)),Some(Vote{yes_votes: 0, no_votes: 0,start: 1100, end:2100,status: VoteStatus::InProgress }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)),Some(Ballot{tally: Tally::default(),start: 1100, end:2100,status: VoteStatus::InProgress }));
 
 		// Add three verifiers 6,7,8
 		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),6,3,b"Verifier1".to_vec()));
@@ -136,17 +151,17 @@ fn it_votes_on_document() {
 		// Member 6 casts vote at block 1200
 
 		run_to_block(1200);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(6),VoteType::Verification,1,true));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(6),VoteType::Verification,1,VoteChoice::Aye));
 
 		// Member 7 casts vote at block 1600
 
 		run_to_block(1600);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(7),VoteType::Verification,1,false));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(7),VoteType::Verification,1,VoteChoice::Nay));
 
 		// Member 8 casts vote at block 2000
 
 		run_to_block(2000);
-		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(8),VoteType::Verification,1,true));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(8),VoteType::Verification,1,VoteChoice::Aye));
 
 		// Finalize The Vote
 
@@ -155,19 +170,28 @@ fn it_votes_on_document() {
 
 		// Check if Vote passed
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)),Some(Vote{yes_votes: 2, no_votes: 1,start: 1100, end:2100,status: VoteStatus::Passed }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)),Some(Ballot{tally: Tally{yes_votes: 2, no_votes: 1, yes_weight: 2, no_weight: 1, ..Default::default()},start: 1100, end:2100,status: VoteStatus::Passed }));
 
 		// Check the upload status
 
-		assert_eq!(BhdaoModule::get_upload(1),Some(Upload{creator: 1, hash: b"Doc1".to_vec(), status: UploadStatus::UnderExpertReview}));
+		assert_eq!(BhdaoModule::get_upload(1),Some(Upload{creator: 1, hash: b"Doc1".to_vec(), status: UploadStatus::UnderExpertReview, categories: vec![]}));
 
-		// Assume no expert objection
+		// Nobody could be drawn for the panel, so the upload stays parked.
 		run_to_block(3300);
+		assert_noop!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1),Error::<Test>::NoEligibleExperts);
+
+		// Once there is an Expert, finalizing draws a panel and starts the review over.
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,4,b"Expert1".to_vec()));
+		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1));
+		assert_eq!(BhdaoModule::get_expert_review(1).unwrap().panel, vec![9]);
+
+		// Assume no expert objection
+		run_to_block(4400);
 		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1));
 
 		// Check the upload status
 
-		assert_eq!(BhdaoModule::get_upload(1),Some(Upload{creator: 1, hash: b"Doc1".to_vec(), status: UploadStatus::Verified}));
+		assert_eq!(BhdaoModule::get_upload(1),Some(Upload{creator: 1, hash: b"Doc1".to_vec(), status: UploadStatus::Verified, categories: vec![]}));
 
 
 	});
@@ -199,28 +223,28 @@ fn it_votes_for_expanded_role() {
 
 		// Contributor 1 applies for verifier role
 
-		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Verifier));
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Verifier,None));
 
 		// Check if the vote is initialized
 
 		assert_eq!(BhdaoModule::role_application_uid_count(),1);
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorVerification,1)),Some(Vote{yes_votes: 0, no_votes: 0,start: 1, end:1001,status: VoteStatus::InProgress }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorVerification,1)),Some(Ballot{tally: Tally::default(),start: 1, end:1001,status: VoteStatus::InProgress }));
 		
 		// Member 6 casts vote at block 200
 
 		run_to_block(200);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CuratorVerification,1,true));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CuratorVerification,1,VoteChoice::Aye));
 
 		// Member 7 casts vote at block 1600
 
 		run_to_block(600);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(7),VoteType::CuratorVerification,1,true));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(7),VoteType::CuratorVerification,1,VoteChoice::Aye));
 
 		// Member 8 casts vote at block 2000
 
 		run_to_block(900);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(8),VoteType::CuratorVerification,1,false));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(8),VoteType::CuratorVerification,1,VoteChoice::Nay));
 
 		// Finalize the vote
 
@@ -229,26 +253,26 @@ fn it_votes_for_expanded_role() {
 
 		// Check if the Vote passed
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorVerification,1)),Some(Vote{yes_votes: 2, no_votes: 1,start: 1, end:1001,status: VoteStatus::Passed }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorVerification,1)),Some(Ballot{tally: Tally{yes_votes: 2, no_votes: 1, yes_weight: 2, no_weight: 1, ..Default::default()},start: 1, end:1001,status: VoteStatus::Passed }));
 
 		// Check if CuratorCouncilApproval voting started 
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorCouncilApproval,1)),Some(Vote{yes_votes: 0, no_votes: 0,start: 1100, end:2100,status: VoteStatus::InProgress }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorCouncilApproval,1)),Some(Ballot{tally: Tally::default(),start: 1100, end:2100,status: VoteStatus::InProgress }));
 
 		// Member 2 casts vote at block 200
 
 		run_to_block(1400);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CuratorCouncilApproval,1,true));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
 
 		// Member 3 casts vote at block 1700
 
 		run_to_block(1700);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(3),VoteType::CuratorCouncilApproval,1,true));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(3),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
 
 		// Member 4 casts vote at block 2000
 
 		run_to_block(2000);
-		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(4),VoteType::CuratorCouncilApproval,1,true));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(4),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
 
 		// Finalize the vote
 
@@ -257,7 +281,1393 @@ fn it_votes_for_expanded_role() {
 
 		// Check if Vote passed
 
-		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorCouncilApproval,1)),Some(Vote{yes_votes: 3, no_votes: 0,start: 1100, end:2100,status: VoteStatus::Passed }));
+		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorCouncilApproval,1)),Some(Ballot{tally: Tally{yes_votes: 3, no_votes: 0, yes_weight: 3, no_weight: 0, ..Default::default()},start: 1100, end:2100,status: VoteStatus::Passed }));
 	});
 }
 
+
+#[test]
+fn it_does_not_grant_a_role_to_a_member_suspended_during_the_vote() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		for expert in [2, 3, 4] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),expert,4,b"Expert".to_vec()));
+		}
+		for verifier in [6, 7, 8] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),verifier,3,b"Verifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Verifier,None));
+		for verifier in [6, 7, 8] {
+			assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(verifier),VoteType::CuratorVerification,1,VoteChoice::Aye));
+		}
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CuratorVerification,1));
+		for expert in [2, 3, 4] {
+			assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(expert),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
+		}
+
+		// The council agrees, but the applicant was suspended in the meantime.
+		assert_ok!(BhdaoModule::suspend_member(RuntimeOrigin::root(),1,b"Misconduct".to_vec(),None));
+		run_to_block(2200);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CuratorCouncilApproval,1));
+		System::assert_has_event(Event::RoleApplicationLapsed { uid: 1, who: 1 }.into());
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Contributor);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn it_adds_genesis_members() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		members: vec![(1, Roles::Expert, b"Expert1".to_vec()), (2, Roles::Verifier, b"Verifier1".to_vec())],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(BhdaoModule::members_uid_count(), 2);
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Expert);
+		assert_eq!(BhdaoModule::get_member(2).unwrap().member_id, 2);
+		assert_eq!(BhdaoModule::get_experts(), vec![1]);
+	});
+}
+
+const CONTENT: &[u8] = b"Doc1 content";
+// CIDv1 (raw, sha2-256) of CONTENT.
+const CONTENT_CID: &[u8] = b"bafkreicckafav7ognptpbjo7p2gf3bfhkcgdnkncsrcaegtzc4vjw2cm2y";
+
+/// A content check of `upload_id` signed by `ATTESTER`.
+fn signed_check(upload_id: u64, available: bool, intact: bool) -> (ContentCheck<UintAuthorityId>, TestSignature) {
+	let check = ContentCheck { upload_id, available, intact, public: UintAuthorityId(ATTESTER) };
+	let signature = TestSignature(ATTESTER, check.encode());
+	(check, signature)
+}
+
+/// The offchain test externalities, answering every request with the status code in `code`
+/// rather than always 200.
+struct GatewayStandIn {
+	ext: testing::TestOffchainExt,
+	code: Arc<AtomicU16>,
+}
+
+impl Externalities for GatewayStandIn {
+	fn is_validator(&self) -> bool {
+		self.ext.is_validator()
+	}
+
+	fn network_state(&self) -> Result<OpaqueNetworkState, ()> {
+		self.ext.network_state()
+	}
+
+	fn timestamp(&mut self) -> Timestamp {
+		self.ext.timestamp()
+	}
+
+	fn sleep_until(&mut self, deadline: Timestamp) {
+		self.ext.sleep_until(deadline)
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		self.ext.random_seed()
+	}
+
+	fn http_request_start(&mut self, method: &str, uri: &str, meta: &[u8]) -> Result<HttpRequestId, ()> {
+		self.ext.http_request_start(method, uri, meta)
+	}
+
+	fn http_request_add_header(&mut self, request_id: HttpRequestId, name: &str, value: &str) -> Result<(), ()> {
+		self.ext.http_request_add_header(request_id, name, value)
+	}
+
+	fn http_request_write_body(&mut self, request_id: HttpRequestId, chunk: &[u8], deadline: Option<Timestamp>) -> Result<(), HttpError> {
+		self.ext.http_request_write_body(request_id, chunk, deadline)
+	}
+
+	fn http_response_wait(&mut self, ids: &[HttpRequestId], deadline: Option<Timestamp>) -> Vec<HttpRequestStatus> {
+		self.ext.http_response_wait(ids, deadline).into_iter().map(|status| match status {
+			HttpRequestStatus::Finished(_) => HttpRequestStatus::Finished(self.code.load(Ordering::SeqCst)),
+			status => status,
+		}).collect()
+	}
+
+	fn http_response_headers(&mut self, request_id: HttpRequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+		self.ext.http_response_headers(request_id)
+	}
+
+	fn http_response_read_body(&mut self, request_id: HttpRequestId, buffer: &mut [u8], deadline: Option<Timestamp>) -> Result<usize, HttpError> {
+		self.ext.http_response_read_body(request_id, buffer, deadline)
+	}
+
+	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
+		self.ext.set_authorized_nodes(nodes, authorized_only)
+	}
+}
+
+/// Expect one fetch of `CONTENT_CID` from the gateway, answered with `body`.
+fn expect_content(state: &mut testing::OffchainState, body: &[u8]) {
+	state.expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: format!("http://localhost:8080/ipfs/{}?format=raw", std::str::from_utf8(CONTENT_CID).unwrap()),
+		response: Some(body.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+fn attest_upload_serving(code: u16, body: &[u8]) -> (bool, bool) {
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut t = new_test_ext();
+	t.register_extension(OffchainWorkerExt::new(GatewayStandIn { ext: offchain.clone(), code: Arc::new(AtomicU16::new(code)) }));
+	t.register_extension(OffchainDbExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+
+	expect_content(&mut offchain_state.write(), body);
+
+	let mut result = (false, false);
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),CONTENT_CID.to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::set_content_gateway(RuntimeOrigin::root(),b"http://localhost:8080/".to_vec()));
+		assert_ok!(BhdaoModule::set_attesters(RuntimeOrigin::root(),vec![ATTESTER]));
+
+		assert_ok!(BhdaoModule::attest_pending_uploads(1));
+
+		// A single unsigned attestation is submitted, and only once within the retry window.
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+
+		let (check, signature) = match tx.call {
+			RuntimeCall::BhdaoModule(crate::Call::submit_content_attestation { check, signature }) => (check, signature),
+			_ => panic!("unexpected call"),
+		};
+		assert_eq!(check.upload_id, 1);
+		let (available, intact) = (check.available, check.intact);
+
+		// Only signed checks from registered attesters are accepted, and never straight from a block.
+		let call = crate::Call::submit_content_attestation { check: check.clone(), signature: signature.clone() };
+		assert_ok!(BhdaoModule::validate_unsigned(TransactionSource::Local, &call));
+		assert_ok!(BhdaoModule::validate_unsigned(TransactionSource::External, &call));
+		assert!(BhdaoModule::validate_unsigned(TransactionSource::InBlock, &call).is_err());
+		assert_ok!(BhdaoModule::pre_dispatch(&call));
+
+		let forged = crate::Call::submit_content_attestation { check: ContentCheck { upload_id: 2, ..check.clone() }, signature: signature.clone() };
+		assert_eq!(BhdaoModule::pre_dispatch(&forged), Err(InvalidTransaction::BadProof.into()));
+		assert_ok!(BhdaoModule::set_attesters(RuntimeOrigin::root(),vec![]));
+		assert_eq!(BhdaoModule::pre_dispatch(&call), Err(InvalidTransaction::BadSigner.into()));
+
+		assert_ok!(BhdaoModule::submit_content_attestation(RuntimeOrigin::none(),check.clone(),signature.clone()));
+		assert_eq!(BhdaoModule::get_content_attestation(1),Some(ContentAttestation{available, intact, checked_at: 1}));
+		System::assert_last_event(Event::ContentAttested { uid: 1, available, intact }.into());
+
+		assert_noop!(BhdaoModule::submit_content_attestation(RuntimeOrigin::none(),check,signature),Error::<Test>::ContentAlreadyAttested);
+		assert_ok!(BhdaoModule::attest_pending_uploads(20));
+		assert!(pool_state.read().transactions.is_empty());
+
+		result = (available, intact);
+	});
+	result
+}
+
+#[test]
+fn it_attests_available_and_intact_content() {
+	assert_eq!(attest_upload_serving(200, CONTENT), (true, true));
+}
+
+#[test]
+fn it_attests_tampered_content() {
+	assert_eq!(attest_upload_serving(200, b"Forged content"), (true, false));
+}
+
+#[test]
+fn it_attests_missing_content() {
+	assert_eq!(attest_upload_serving(404, b""), (false, false));
+}
+
+#[test]
+fn it_audits_verified_uploads() {
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let code = Arc::new(AtomicU16::new(200));
+	let mut t = new_test_ext();
+	t.register_extension(OffchainWorkerExt::new(GatewayStandIn { ext: offchain.clone(), code: code.clone() }));
+	t.register_extension(OffchainDbExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+
+	expect_content(&mut offchain_state.write(), CONTENT);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		crate::Uploads::<Test>::insert(1, Upload { creator: 1, hash: CONTENT_CID.to_vec(), status: UploadStatus::Verified, categories: vec![] });
+		crate::UploadCount::<Test>::put(1);
+		assert_ok!(BhdaoModule::set_content_gateway(RuntimeOrigin::root(),b"http://localhost:8080".to_vec()));
+
+		run_to_block(100);
+		assert_ok!(BhdaoModule::audit_verified_uploads(100));
+		let tx = Extrinsic::decode(&mut &*pool_state.write().transactions.pop().unwrap()).unwrap();
+		let (check, signature) = signed_check(1, true, true);
+		assert_eq!(tx.call, RuntimeCall::BhdaoModule(crate::Call::submit_content_audit { check: check.clone(), signature: signature.clone() }));
+
+		assert_ok!(BhdaoModule::submit_content_audit(RuntimeOrigin::none(),check.clone(),signature.clone()));
+		assert_eq!(BhdaoModule::get_content_audit(1).unwrap().last_seen_available, Some(100));
+		assert_noop!(BhdaoModule::submit_content_audit(RuntimeOrigin::none(),check,signature),Error::<Test>::AlreadyAuditedThisEpoch);
+
+		// Later runs in the same epoch have nothing left to audit.
+		assert_ok!(BhdaoModule::audit_verified_uploads(150));
+		assert!(pool_state.read().transactions.is_empty());
+
+		// Three failed audits in a row mark the upload at risk once the gateway lost the content.
+		code.store(404, Ordering::SeqCst);
+		for block in [200, 300, 400] {
+			run_to_block(block);
+			expect_content(&mut offchain_state.write(), b"");
+			assert_ok!(BhdaoModule::audit_verified_uploads(block));
+			let tx = Extrinsic::decode(&mut &*pool_state.write().transactions.pop().unwrap()).unwrap();
+			let (check, signature) = signed_check(1, false, false);
+			assert_eq!(tx.call, RuntimeCall::BhdaoModule(crate::Call::submit_content_audit { check: check.clone(), signature: signature.clone() }));
+			assert_ok!(BhdaoModule::submit_content_audit(RuntimeOrigin::none(),check,signature));
+		}
+		System::assert_has_event(Event::ContentUnavailable { uid: 1, failures: 3 }.into());
+
+		let audit = BhdaoModule::get_content_audit(1).unwrap();
+		assert!(audit.at_risk);
+		assert_eq!(audit.last_seen_available, Some(100));
+
+		// Recovery clears the flag.
+		run_to_block(500);
+		let (check, signature) = signed_check(1, true, true);
+		assert_ok!(BhdaoModule::submit_content_audit(RuntimeOrigin::none(),check,signature));
+		assert!(!BhdaoModule::get_content_audit(1).unwrap().at_risk);
+	});
+}
+
+#[test]
+fn it_weights_votes_by_reputation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,1,b"Qualifier1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),3,1,b"Qualifier2".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),4,1,b"Qualifier3".to_vec()));
+
+		// Member 2 has a track record.
+		BhdaoModule::adjust_reputation(&2, true, 4);
+
+		assert_ok!(BhdaoModule::set_vote_weighting(RuntimeOrigin::root(),VoteType::Qualification,VoteWeighting::Reputation));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+
+		run_to_block(200);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,VoteChoice::Nay));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Qualification,1,VoteChoice::Nay));
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Qualification,1,VoteChoice::Nay),Error::<Test>::AlreadyVoted);
+
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1));
+
+		// Outvoted by head count, but not by weight.
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)),Some(Ballot{tally: Tally{yes_votes: 1, no_votes: 2, yes_weight: 5, no_weight: 2, ..Default::default()},start: 1, end:1001,status: VoteStatus::Passed }));
+	});
+}
+
+#[test]
+fn it_weights_votes_by_conviction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,1,b"Qualifier1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),3,1,b"Qualifier2".to_vec()));
+
+		// Council ballots can't be weighted.
+		assert_noop!(BhdaoModule::set_vote_weighting(RuntimeOrigin::root(),VoteType::ExpertCouncilApproval,VoteWeighting::Conviction),Error::<Test>::WeightingNotAllowed);
+
+		assert_ok!(BhdaoModule::set_vote_weighting(RuntimeOrigin::root(),VoteType::Qualification,VoteWeighting::Conviction));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+
+		run_to_block(200);
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye),Error::<Test>::ConvictionVoteRequired);
+		assert_noop!(BhdaoModule::cast_conviction_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye,Conviction::Locked1x,INITIAL_BALANCE + 1),Error::<Test>::InsufficientBalance);
+
+		assert_ok!(BhdaoModule::cast_conviction_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye,Conviction::Locked3x,1_000));
+		assert_ok!(BhdaoModule::cast_conviction_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,VoteChoice::Nay,Conviction::None,5_000));
+
+		let vote = BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap();
+		assert_eq!((vote.tally.yes_weight, vote.tally.no_weight), (3_000, 500));
+
+		// The locked balance can't be moved.
+		assert_eq!(Balances::usable_balance(2), INITIAL_BALANCE - 1_000);
+
+		// Locked for three voting windows after the ballot ends.
+		assert_eq!(BhdaoModule::get_voting_lock_expiry(2), Some(1001 + 3 * 1000));
+		run_to_block(1100);
+		assert_noop!(BhdaoModule::unlock_voting_balance(RuntimeOrigin::signed(2)),Error::<Test>::VotingLockNotExpired);
+		assert_ok!(BhdaoModule::unlock_voting_balance(RuntimeOrigin::signed(3)));
+
+		run_to_block(4002);
+		assert_ok!(BhdaoModule::unlock_voting_balance(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::usable_balance(2), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn it_counts_delegated_votes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		// Qualifiers 2,3,4,5
+		for who in 2..=5 {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,1,b"Qualifier".to_vec()));
+		}
+
+		assert_noop!(BhdaoModule::delegate(RuntimeOrigin::signed(3),3,Some(VoteType::Qualification)),Error::<Test>::CannotDelegateToSelf);
+		assert_noop!(BhdaoModule::delegate(RuntimeOrigin::signed(3),2,Some(VoteType::Verification)),Error::<Test>::NotEligibleToVerify);
+
+		// 3 delegates Qualification, 4 delegates across the board which for a Qualifier is Qualification only.
+		assert_ok!(BhdaoModule::delegate(RuntimeOrigin::signed(3),2,Some(VoteType::Qualification)));
+		assert_ok!(BhdaoModule::delegate(RuntimeOrigin::signed(4),2,None));
+		assert_eq!(BhdaoModule::get_delegation(4,VoteType::Verification), None);
+		assert_eq!(BhdaoModule::get_delegated_power(2,VoteType::Qualification), DelegatedPower { delegators: 2, reputation: 2 });
+
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc2".to_vec(),vec![],None));
+
+		run_to_block(200);
+
+		// Delegate votes first, then a delegator overrides it.
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap().tally.yes_weight, 3);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,VoteChoice::Nay));
+		let vote = BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap();
+		assert_eq!((vote.tally.yes_votes, vote.tally.no_votes, vote.tally.yes_weight, vote.tally.no_weight), (1, 1, 2, 1));
+		// The delegate's open ballot keeps the delegated weight still applied on it.
+		assert_eq!(BhdaoModule::get_open_ballots(2,VoteType::Qualification), vec![(1, VoteChoice::Aye, 1)]);
+
+		// Delegator votes first, the delegate's later vote leaves it out.
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,2,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,2,VoteChoice::Nay));
+		let vote = BhdaoModule::get_vote((VoteType::Qualification,2)).unwrap();
+		assert_eq!((vote.tally.yes_weight, vote.tally.no_weight), (1, 2));
+
+		// Revoking takes 4's weight back from every open ballot its delegate voted on.
+		assert_ok!(BhdaoModule::undelegate(RuntimeOrigin::signed(4),None));
+		assert_noop!(BhdaoModule::undelegate(RuntimeOrigin::signed(4),None),Error::<Test>::NotDelegating);
+		System::assert_last_event(Event::Undelegated { who: 4, vote_type: VoteType::Qualification }.into());
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap().tally.yes_weight, 1);
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,2)).unwrap().tally.no_weight, 1);
+		assert_eq!(BhdaoModule::get_delegated_power(2,VoteType::Qualification), DelegatedPower { delegators: 1, reputation: 1 });
+		assert_eq!(BhdaoModule::get_open_ballots(2,VoteType::Qualification), vec![(1, VoteChoice::Aye, 0), (2, VoteChoice::Nay, 0)]);
+
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(5),VoteType::Qualification,1,VoteChoice::Nay));
+
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap().status, VoteStatus::Failed);
+	});
+}
+
+#[test]
+fn it_counts_only_revealed_votes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Verifier 1 applies for the Expert role, verifiers 6,7,8 vote on it.
+		for who in [1, 6, 7, 8] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+
+		assert_noop!(BhdaoModule::set_commit_reveal(RuntimeOrigin::root(),VoteType::Qualification,true),Error::<Test>::WrongVoteType);
+		assert_ok!(BhdaoModule::set_commit_reveal(RuntimeOrigin::root(),VoteType::ExpertVerification,true));
+		assert_ok!(BhdaoModule::add_domain(RuntimeOrigin::root(),b"Civil Rights".to_vec()));
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Expert,Some(1)));
+
+		run_to_block(200);
+		assert_noop!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,VoteChoice::Aye),Error::<Test>::CommitRevealRequired);
+
+		assert_ok!(BhdaoModule::commit_vote(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,BhdaoModule::vote_commitment(&6,VoteChoice::Aye,b"salt6")));
+		assert_ok!(BhdaoModule::commit_vote(RuntimeOrigin::signed(7),VoteType::ExpertVerification,1,BhdaoModule::vote_commitment(&7,VoteChoice::Nay,b"salt7")));
+		assert_ok!(BhdaoModule::commit_vote(RuntimeOrigin::signed(8),VoteType::ExpertVerification,1,BhdaoModule::vote_commitment(&8,VoteChoice::Aye,b"salt8")));
+
+		// Nothing is tallied until the reveal window.
+		assert_eq!(BhdaoModule::get_vote((VoteType::ExpertVerification,1)).unwrap().tally.yes_votes, 0);
+		assert_noop!(BhdaoModule::reveal_vote(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,VoteChoice::Aye,b"salt6".to_vec()),Error::<Test>::RevealWindowNotValid);
+
+		run_to_block(1001);
+		assert_noop!(BhdaoModule::reveal_vote(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,VoteChoice::Nay,b"salt6".to_vec()),Error::<Test>::CommitmentMismatch);
+		assert_ok!(BhdaoModule::reveal_vote(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,VoteChoice::Aye,b"salt6".to_vec()));
+		assert_ok!(BhdaoModule::reveal_vote(RuntimeOrigin::signed(7),VoteType::ExpertVerification,1,VoteChoice::Nay,b"salt7".to_vec()));
+
+		run_to_block(1100);
+		assert_noop!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(1),VoteType::ExpertVerification,1),Error::<Test>::VoteStillInProgress);
+
+		// 8 never reveals, so its yes is an abstention and the ballot ties.
+		run_to_block(1300);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(1),VoteType::ExpertVerification,1));
+		System::assert_has_event(Event::VotesUnrevealed { vote_type: VoteType::ExpertVerification, uid: 1, count: 1 }.into());
+		assert_eq!(BhdaoModule::get_vote((VoteType::ExpertVerification,1)),Some(Ballot{tally: Tally{yes_votes: 1, no_votes: 1, yes_weight: 1, no_weight: 1, ..Default::default()},start: 1, end:1001,status: VoteStatus::Failed }));
+		assert_eq!(BhdaoModule::get_missed_reveals(8), 1);
+		assert_eq!(BhdaoModule::get_missed_reveals(6), 0);
+		assert_eq!(BhdaoModule::get_member(8).unwrap().vote_count, 0);
+	});
+}
+
+#[test]
+fn it_adjudicates_expert_objections() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Contributor 1, experts 2,3,4,5
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		for who in 2..=5 {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+
+		// Two uploads already through Verification.
+		for uid in 1..=2 {
+			crate::Uploads::<Test>::insert(uid, Upload{creator: 1, hash: b"Doc".to_vec(), status: UploadStatus::UnderExpertReview, categories: vec![]});
+			crate::ExpertReviews::<Test>::insert(uid, ExpertReview{start: 1, end: 1001, objections: None, panel: vec![2, 3, 4, 5], responded: vec![]});
+		}
+
+		run_to_block(100);
+		assert_noop!(BhdaoModule::raise_expert_objection(RuntimeOrigin::signed(2),1,b"Reason".to_vec(),ObjectionCategory::Copyright,vec![b"Evidence".to_vec(); 9]),Error::<Test>::TooMuchEvidence);
+		assert_ok!(BhdaoModule::raise_expert_objection(RuntimeOrigin::signed(2),1,b"Reason1".to_vec(),ObjectionCategory::Copyright,vec![b"Evidence1".to_vec()]));
+		assert_ok!(BhdaoModule::raise_expert_objection(RuntimeOrigin::signed(3),2,b"Reason2".to_vec(),ObjectionCategory::FactualError,vec![b"Evidence2".to_vec()]));
+		assert_eq!(Balances::reserved_balance(2), OBJECTION_DEPOSIT);
+
+		// The contributor answers once.
+		assert_noop!(BhdaoModule::respond_to_objection(RuntimeOrigin::signed(3),1,b"Response".to_vec()),Error::<Test>::NotUploadCreator);
+		assert_ok!(BhdaoModule::respond_to_objection(RuntimeOrigin::signed(1),1,b"Response".to_vec()));
+		assert_noop!(BhdaoModule::respond_to_objection(RuntimeOrigin::signed(1),1,b"Response".to_vec()),Error::<Test>::AlreadyResponded);
+
+		// Objection 1 is dismissed, objection 2 upheld.
+		assert_noop!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(2),1,VoteChoice::Aye),Error::<Test>::ObjectorCannotAdjudicate);
+		assert_ok!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(3),1,VoteChoice::Nay));
+		assert_ok!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(4),1,VoteChoice::Nay));
+		assert_ok!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(2),2,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(4),2,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::adjudicate_objection(RuntimeOrigin::signed(5),2,VoteChoice::Nay));
+
+		// The review window is over but the objection ballots are not.
+		run_to_block(1050);
+		assert_noop!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1),Error::<Test>::VoteStillInProgress);
+
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1));
+		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),2));
+		assert_noop!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1),Error::<Test>::NotUnderExpertReview);
+
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::Verified);
+		assert_eq!(BhdaoModule::get_upload(2).unwrap().status, UploadStatus::Rejected);
+
+		let objection = BhdaoModule::get_expert_review(1).unwrap().objections.unwrap()[0].clone();
+		assert_eq!((objection.status, objection.response), (ObjectionStatus::Dismissed, Some(b"Response".to_vec())));
+		System::assert_has_event(Event::ObjectionResolved { uid: 2, objection_id: 2, upheld: true }.into());
+
+		// Dismissed deposit slashed, upheld deposit returned.
+		assert_eq!(Balances::total_balance(&2), INITIAL_BALANCE - OBJECTION_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn it_appeals_a_rejected_upload() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Contributor 1, qualifiers 2,3, experts 4,5,6,7,8
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,1,b"Qualifier1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),3,1,b"Qualifier2".to_vec()));
+		for who in 4..=8 {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc2".to_vec(),vec![],None));
+
+		run_to_block(200);
+		for uid in 1..=2 {
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,uid,VoteChoice::Nay));
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,uid,VoteChoice::Nay));
+		}
+		// Expert 4 took part in the original review.
+		crate::CheckVote::<Test>::insert((4,VoteType::Qualification,1),true);
+
+		run_to_block(1100);
+		for uid in 1..=2 {
+			assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,uid));
+		}
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::Rejected);
+		assert_eq!(BhdaoModule::get_rejection(1), Some(Rejection{stage: UploadStatus::QualificationVoteInProgress, at: 1100, appealed: false}));
+		// 1 had no reputation to lose.
+		assert_eq!(BhdaoModule::get_rejection_penalty(1), Some(0));
+
+		assert_noop!(BhdaoModule::file_appeal(RuntimeOrigin::signed(2),1),Error::<Test>::NotUploadCreator);
+		assert_ok!(BhdaoModule::file_appeal(RuntimeOrigin::signed(1),1));
+		assert_noop!(BhdaoModule::file_appeal(RuntimeOrigin::signed(1),1),Error::<Test>::AlreadyAppealed);
+		assert_eq!(Balances::reserved_balance(1), APPEAL_DEPOSIT);
+
+		let panel = BhdaoModule::get_appeal(1).unwrap().panel;
+		assert_eq!(panel.len(), 3);
+		assert!(panel.iter().all(|who| (5..=8).contains(who)));
+		let outsider = (4..=8).find(|who| !panel.contains(who)).unwrap();
+
+		run_to_block(1200);
+		assert_noop!(BhdaoModule::cast_appeal_vote(RuntimeOrigin::signed(outsider),1,VoteChoice::Aye),Error::<Test>::NotOnPanel);
+		assert_ok!(BhdaoModule::cast_appeal_vote(RuntimeOrigin::signed(panel[0]),1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_appeal_vote(RuntimeOrigin::signed(panel[1]),1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_appeal_vote(RuntimeOrigin::signed(panel[2]),1,VoteChoice::Nay));
+
+		// Too late to appeal the second upload.
+		run_to_block(2200);
+		assert_noop!(BhdaoModule::file_appeal(RuntimeOrigin::signed(1),2),Error::<Test>::AppealPeriodOver);
+
+		// The upload goes on to Verification and the deposit is returned.
+		assert_ok!(BhdaoModule::finalize_appeal(RuntimeOrigin::signed(1),1));
+		System::assert_last_event(Event::AppealResolved { uid: 1, appeal_id: 1, passed: true }.into());
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::VerificationVoteInProgress);
+		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)),Some(Ballot{tally: Tally::default(),start: 2200, end:3200,status: VoteStatus::InProgress }));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(BhdaoModule::get_upload_appeals(1), vec![1]);
+		assert_eq!(BhdaoModule::get_appeal(1).unwrap().status, VoteStatus::Passed);
+		assert_eq!(BhdaoModule::reputation_of(&1), 0);
+	});
+}
+
+#[test]
+fn it_assigns_expert_review_panels() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Contributor 1, experts 2,3,4,5,6
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		for who in 2..=6 {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		assert_ok!(BhdaoModule::declare_conflict(RuntimeOrigin::signed(2),1));
+
+		crate::Uploads::<Test>::insert(1, Upload{creator: 1, hash: b"Doc1".to_vec(), status: UploadStatus::VerificationVoteInProgress, categories: vec![]});
+		assert_ok!(BhdaoModule::start_expert_review(1));
+
+		let panel = BhdaoModule::get_expert_review(1).unwrap().panel;
+		assert_eq!(panel.len(), 3);
+		assert!(!panel.contains(&2));
+		System::assert_last_event(Event::ReviewPanelAssigned { uid: 1, panel: panel.clone() }.into());
+		let outsider = (3..=6).find(|who| !panel.contains(who)).unwrap();
+
+		run_to_block(100);
+		assert_noop!(BhdaoModule::raise_expert_objection(RuntimeOrigin::signed(outsider),1,b"Reason".to_vec(),ObjectionCategory::Authenticity,vec![]),Error::<Test>::NotOnPanel);
+		assert_noop!(BhdaoModule::sign_off_review(RuntimeOrigin::signed(2),1),Error::<Test>::NotOnPanel);
+
+		assert_ok!(BhdaoModule::sign_off_review(RuntimeOrigin::signed(panel[0]),1));
+		assert_noop!(BhdaoModule::sign_off_review(RuntimeOrigin::signed(panel[0]),1),Error::<Test>::AlreadyRespondedToReview);
+		assert_ok!(BhdaoModule::raise_expert_objection(RuntimeOrigin::signed(panel[1]),1,b"Reason".to_vec(),ObjectionCategory::Authenticity,vec![]));
+
+		// The third panelist never responds.
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1));
+		System::assert_has_event(Event::PanelistPenalized { uid: 1, who: panel[2] }.into());
+		assert_eq!(BhdaoModule::get_member(panel[2]).unwrap().penalties, 1);
+		assert_eq!(BhdaoModule::get_member(panel[0]).unwrap().penalties, 0);
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::Verified);
+
+		assert_ok!(BhdaoModule::withdraw_conflict(RuntimeOrigin::signed(2),1));
+		assert_noop!(BhdaoModule::withdraw_conflict(RuntimeOrigin::signed(2),1),Error::<Test>::NoConflict);
+	});
+}
+
+#[test]
+fn it_matches_experts_by_domain() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::add_domain(RuntimeOrigin::root(),b"Music".to_vec()));
+		assert_ok!(BhdaoModule::add_domain(RuntimeOrigin::root(),b"Law".to_vec()));
+
+		// Verifiers 1,6,7, experts 2,3, contributor 9
+		for who in [1, 6, 7] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+		for who in [2, 3] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Expert,None),Error::<Test>::DomainRequired);
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Expert,Some(9)),Error::<Test>::UnknownDomain);
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Expert,Some(1)));
+		System::assert_last_event(Event::ExpertiseClaimed { uid: 1, who: 1, domain: 1 }.into());
+
+		run_to_block(200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(7),VoteType::ExpertVerification,1,VoteChoice::Aye));
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::ExpertVerification,1));
+
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::ExpertCouncilApproval,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(3),VoteType::ExpertCouncilApproval,1,VoteChoice::Aye));
+		run_to_block(2200);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::ExpertCouncilApproval,1));
+
+		// The applicant is promoted, not whoever finalized.
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Expert);
+		assert_eq!(BhdaoModule::get_member(6).unwrap().role, Roles::Verifier);
+		assert_eq!(BhdaoModule::experts_in_domain(1), vec![1]);
+		assert_eq!(BhdaoModule::domains_of(&1), vec![1]);
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Expert,Some(1)),Error::<Test>::AlreadyDomainExpert);
+
+		// Law uploads go to the Law expert.
+		crate::ExpertDomains::<Test>::insert(2, 2, true);
+		assert_noop!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc1".to_vec(),vec![3],None),Error::<Test>::UnknownDomain);
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc1".to_vec(),vec![2],None));
+		crate::Uploads::<Test>::mutate(1, |upload| upload.as_mut().unwrap().status = UploadStatus::VerificationVoteInProgress);
+		assert_ok!(BhdaoModule::start_expert_review(1));
+		assert_eq!(BhdaoModule::get_expert_review(1).unwrap().panel, vec![2]);
+	});
+}
+
+#[test]
+fn it_suspends_demotes_and_removes_members() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Experts 1,2,3, contributor 4, verifier 5
+		for who in [1, 2, 3] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),4,2,b"Contributor".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),5,3,b"Verifier".to_vec()));
+
+		// Emergency suspension blocks uploads and votes until it lapses.
+		assert_ok!(BhdaoModule::suspend_member(RuntimeOrigin::root(),4,b"Spam".to_vec(),Some(50)));
+		System::assert_last_event(Event::MemberSuspended { who: 4, until: Some(50), reason: b"Spam".to_vec() }.into());
+		assert_noop!(BhdaoModule::upload_document(RuntimeOrigin::signed(4),b"Doc1".to_vec(),vec![],None),Error::<Test>::Suspended);
+		run_to_block(50);
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(4),b"Doc1".to_vec(),vec![],None));
+
+		assert_noop!(BhdaoModule::propose_member_action(RuntimeOrigin::signed(1),4,MemberAction::Demote(Roles::Verifier),b"Plagiarism".to_vec()),Error::<Test>::InvalidDemotion);
+		assert_ok!(BhdaoModule::propose_member_action(RuntimeOrigin::signed(1),5,MemberAction::Demote(Roles::Qualifier),b"Inactive".to_vec()));
+		assert_ok!(BhdaoModule::propose_member_action(RuntimeOrigin::signed(1),4,MemberAction::Remove,b"Plagiarism".to_vec()));
+		assert_ok!(BhdaoModule::propose_member_action(RuntimeOrigin::signed(2),5,MemberAction::Suspend(None),b"Spam".to_vec()));
+		assert_eq!(Balances::reserved_balance(1), 2 * MEMBER_ACTION_DEPOSIT);
+		assert_ok!(BhdaoModule::delegate(RuntimeOrigin::signed(5),4,Some(VoteType::Qualification)));
+
+		run_to_block(100);
+		assert_ok!(BhdaoModule::suspend_member(RuntimeOrigin::root(),3,b"Abuse".to_vec(),None));
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Proposal,1,VoteChoice::Nay),Error::<Test>::Suspended);
+		assert_noop!(BhdaoModule::resign(RuntimeOrigin::signed(3)),Error::<Test>::Suspended);
+		assert_ok!(BhdaoModule::lift_suspension(RuntimeOrigin::root(),3));
+		// Only Experts vote on member actions.
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(5),VoteType::Proposal,1,VoteChoice::Nay),Error::<Test>::NotAnExpert);
+		assert_noop!(BhdaoModule::lift_suspension(RuntimeOrigin::root(),3),Error::<Test>::NotSuspended);
+		for id in [1, 2] {
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(1),VoteType::Proposal,id,VoteChoice::Aye));
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Proposal,id,VoteChoice::Aye));
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Proposal,id,VoteChoice::Nay));
+		}
+		// A lone aye falls short of the quorum of half the Experts.
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Proposal,3,VoteChoice::Aye));
+		// Power can't be delegated on proposals, so they don't take up open ballot slots.
+		assert!(BhdaoModule::get_open_ballots(2,VoteType::Proposal).is_empty());
+
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Proposal,1));
+		System::assert_last_event(Event::MemberDemoted { who: 5, role: Roles::Qualifier }.into());
+		assert_eq!(BhdaoModule::get_member(5).unwrap().role, Roles::Qualifier);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Proposal,2));
+		System::assert_last_event(Event::MemberRemoved { who: 4 }.into());
+		assert!(BhdaoModule::get_member(4).is_none());
+		assert!(BhdaoModule::get_delegation(5, VoteType::Qualification).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(BhdaoModule::join_dao(RuntimeOrigin::signed(4),b"Again".to_vec()),Error::<Test>::AccountBanned);
+
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Proposal,3));
+		System::assert_last_event(Event::MemberActionDepositSlashed { uid: 3, who: 2, amount: MEMBER_ACTION_DEPOSIT }.into());
+		assert!(BhdaoModule::get_suspension(5).is_none());
+
+		assert_ok!(BhdaoModule::resign(RuntimeOrigin::signed(3)));
+		System::assert_last_event(Event::MemberResigned { who: 3 }.into());
+		assert_noop!(BhdaoModule::resign(RuntimeOrigin::signed(3)),Error::<Test>::NotAMember);
+	});
+}
+
+#[test]
+fn it_onboards_collectors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Qualifier 1, experts 2,3, verifiers 6,7, contributor 9
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,1,b"Museum".to_vec()));
+		for who in [2, 3] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		for who in [6, 7] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+
+		assert_noop!(BhdaoModule::apply_for_collector_role(RuntimeOrigin::signed(9),b"KYC".to_vec()),Error::<Test>::NotEligibleForCollectorRole);
+		assert_ok!(BhdaoModule::apply_for_collector_role(RuntimeOrigin::signed(1),b"KYC".to_vec()));
+		System::assert_has_event(Event::CollectorApplied { uid: 1, who: 1 }.into());
+
+		assert_noop!(BhdaoModule::attest_collector_kyc(RuntimeOrigin::signed(6),1),Error::<Test>::NotAnExpert);
+		assert_ok!(BhdaoModule::attest_collector_kyc(RuntimeOrigin::signed(2),1));
+		assert_noop!(BhdaoModule::attest_collector_kyc(RuntimeOrigin::signed(2),1),Error::<Test>::AlreadyAttested);
+
+		run_to_block(200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CollectorVerification,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(7),VoteType::CollectorVerification,1,VoteChoice::Aye));
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CollectorVerification,1));
+
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CollectorCouncilApproval,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(3),VoteType::CollectorCouncilApproval,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::attest_collector_kyc(RuntimeOrigin::signed(3),1));
+		run_to_block(2200);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CollectorCouncilApproval,1));
+		System::assert_last_event(Event::CollectorApproved { who: 1 }.into());
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Collector);
+
+		// Collectors sponsor uploads and commission verification of their own holdings.
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc2".to_vec(),vec![],None));
+		assert_noop!(BhdaoModule::sponsor_upload(RuntimeOrigin::signed(9),1,100),Error::<Test>::NotACollector);
+		assert_ok!(BhdaoModule::sponsor_upload(RuntimeOrigin::signed(1),1,100));
+		assert_eq!(Balances::reserved_balance(1), 100);
+
+		assert_ok!(BhdaoModule::verify_upload(1));
+		System::assert_has_event(Event::SponsorshipReleased { uid: 1, who: 1, amount: 100, paid: true }.into());
+		assert_eq!(Balances::free_balance(9), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		let token = BhdaoModule::token_uid_count();
+		assert!(BhdaoModule::has_early_access(&1, token));
+		assert!(!BhdaoModule::has_early_access(&10, token));
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(9),9,10,token,1),pallet_nft::Error::<Test>::RecipientNotAllowed);
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(9),9,1,token,1));
+		run_to_block(2200 + COLLECTOR_EARLY_ACCESS as u64);
+		assert!(BhdaoModule::has_early_access(&10, token));
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(9),9,10,token,1));
+	});
+}
+
+#[test]
+fn it_tracks_member_reputation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Qualifiers 1,2, contributor 9
+		for who in [1, 2] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,1,b"Qualifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+		for _ in 0..5 {
+			assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc".to_vec(),vec![],None));
+		}
+
+		// 1 backs the four uploads that pass, 2 sinks the fifth.
+		run_to_block(100);
+		for uid in 1..=4 {
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(1),VoteType::Qualification,uid,VoteChoice::Aye));
+		}
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,5,VoteChoice::Nay));
+		// Voting alone no longer promotes.
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Qualifier);
+
+		run_to_block(1100);
+		for uid in 1..=4 {
+			assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,uid));
+		}
+		assert_eq!(BhdaoModule::reputation_of(&1), CONTRIBUTOR_THRESHOLD);
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Contributor);
+		System::assert_has_event(Event::MemberPromoted { who: 1, role: Roles::Contributor }.into());
+
+		// Verified uploads credit the contributor, rejected ones cost reputation.
+		assert_ok!(BhdaoModule::verify_upload(1));
+		assert_eq!(BhdaoModule::get_member(9).unwrap().approved_contributions, 1);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,5));
+		assert_eq!(BhdaoModule::reputation_of(&9), 5 - 2);
+		assert_eq!(BhdaoModule::reputation_of(&2), 1);
+		assert_eq!(BhdaoModule::get_member(2).unwrap().role, Roles::Qualifier);
+
+		// Half of it decays every period.
+		run_to_block(1100 + REPUTATION_DECAY_PERIOD as u64);
+		assert_eq!(BhdaoModule::reputation_of(&1), CONTRIBUTOR_THRESHOLD / 2);
+	});
+}
+
+#[test]
+fn it_settles_reputation_in_idle_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+		for who in 1..=6 {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,1,b"Qualifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc".to_vec(),vec![],None));
+
+		run_to_block(100);
+		for who in 1..=6 {
+			assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(who),VoteType::Qualification,1,VoteChoice::Aye));
+		}
+
+		// Finalizing settles `MaxSettlements` votes and leaves the rest for idle blocks.
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(9),VoteType::Qualification,1));
+		assert_eq!((1..=6).filter(|who| BhdaoModule::reputation_of(who) == 1).count(), MAX_SETTLEMENTS as usize);
+		assert_eq!(BhdaoModule::get_pending_settlements(), vec![(VoteType::Qualification, 1, true)]);
+
+		run_to_block(1101);
+		assert!((1..=6).all(|who| BhdaoModule::reputation_of(&who) == 1));
+		assert!(BhdaoModule::get_pending_settlements().is_empty());
+	});
+}
+
+#[test]
+fn it_applies_role_requirements() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,1,b"Qualifier".to_vec()));
+
+		let requirement = RoleRequirement { from_role: Roles::Contributor, min_tenure: 100, min_reputation: 0, min_approved_contributions: 1, cooldown: 500 };
+		assert_noop!(BhdaoModule::set_role_requirement(RuntimeOrigin::root(),Roles::Qualifier,requirement.clone()),Error::<Test>::WrongRoleApplied);
+		assert_ok!(BhdaoModule::set_role_requirement(RuntimeOrigin::root(),Roles::Verifier,requirement.clone()));
+		System::assert_last_event(Event::RoleRequirementSet { role: Roles::Verifier, requirement }.into());
+
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Verifier,None),Error::<Test>::NotEligibleForVerifierRole);
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(9),Roles::Verifier,None),Error::<Test>::RequirementsNotMet);
+		assert_eq!(
+			BhdaoModule::unmet_requirements(&9, Roles::Verifier),
+			vec![UnmetRequirement::Tenure(100), UnmetRequirement::ApprovedContributions(1)]
+		);
+		let progress = BhdaoModule::role_progress(&1);
+		assert!(progress.contains(&(Roles::Verifier, vec![UnmetRequirement::Role(Roles::Contributor), UnmetRequirement::Tenure(100), UnmetRequirement::ApprovedContributions(1)])));
+		assert!(progress.contains(&(Roles::Contributor, vec![UnmetRequirement::Reputation(CONTRIBUTOR_THRESHOLD)])));
+
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::verify_upload(1));
+		run_to_block(101);
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(9),Roles::Verifier,None));
+
+		// Another application has to wait out the cooldown.
+		run_to_block(201);
+		assert_eq!(BhdaoModule::unmet_requirements(&9, Roles::Verifier), vec![UnmetRequirement::Cooldown(400)]);
+		assert_noop!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(9),Roles::Verifier,None),Error::<Test>::RequirementsNotMet);
+	});
+}
+
+#[test]
+fn it_times_ballots_per_vote_type() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for who in [1, 2] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,1,b"Qualifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,2,b"Contributor".to_vec()));
+
+		let timing = BallotTiming { window: 100, delay: 10, late_period: 20, extension: 50, max_extensions: 1 };
+		assert_noop!(BhdaoModule::set_ballot_timing(RuntimeOrigin::root(),VoteType::Qualification,BallotTiming { window: 0, ..timing.clone() }),Error::<Test>::InvalidTiming);
+		assert_ok!(BhdaoModule::set_ballot_timing(RuntimeOrigin::root(),VoteType::Qualification,timing.clone()));
+		System::assert_last_event(Event::BallotTimingSet { vote_type: VoteType::Qualification, timing }.into());
+		assert_ok!(BhdaoModule::set_expert_review_window(RuntimeOrigin::root(),5000));
+
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(9),b"Doc1".to_vec(),vec![],None));
+		let vote = BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap();
+		assert_eq!((vote.start, vote.end), (11, 111));
+
+		// Voting opens after the delay.
+		run_to_block(5);
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1,VoteChoice::Aye),Error::<Test>::VotingWindowNotValid);
+
+		// A late vote extends the ballot once.
+		run_to_block(100);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1,VoteChoice::Aye));
+		System::assert_has_event(Event::BallotExtended { vote_type: VoteType::Qualification, uid: 1, end: 161 }.into());
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap().end, 161);
+
+		run_to_block(162);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1));
+		// Verification keeps the default window.
+		assert_eq!(BhdaoModule::get_vote((VoteType::Verification,1)).unwrap().end, 162 + VOTING_WINDOW as u64);
+
+		assert_ok!(BhdaoModule::start_expert_review(1));
+		assert_eq!(BhdaoModule::get_expert_review(1).unwrap().end, 162 + 5000);
+	});
+}
+
+#[test]
+fn it_opens_ballots_on_their_start_block_and_closes_them_on_their_end_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for who in [2, 3] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,1,b"Qualifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+
+		// Voting is open from the block the ballot is created in...
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Qualification,1,VoteChoice::Aye));
+		assert_noop!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1),Error::<Test>::VoteStillInProgress);
+
+		// ...up to the block before `end`.
+		run_to_block(VOTING_WINDOW as u64);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Qualification,1,VoteChoice::Aye));
+		assert_noop!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1),Error::<Test>::VoteStillInProgress);
+
+		// From `end` on it can only be finalized.
+		run_to_block(1 + VOTING_WINDOW as u64);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(1),VoteType::Qualification,1));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)).unwrap().tally.yes_votes, 2);
+	});
+}
+
+#[test]
+fn it_hands_finalized_ballots_to_the_handler() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let mut ballot = Ballot::new(10u64, 20u64);
+		assert_eq!(ballot.ensure_open(9), Err(BallotError::NotOpen));
+		assert_ok!(ballot.ensure_open(10));
+		ballot.tally.add(VoteChoice::Aye, 3);
+		ballot.tally.add(VoteChoice::Nay, 2);
+		assert_eq!(ballot.ensure_closed(19), Err(BallotError::StillOpen));
+		assert_ok!(ballot.ensure_closed(20));
+		ballot.conclude(ballot.passed());
+		assert_eq!(ballot.status, VoteStatus::Passed);
+		assert_eq!(ballot.ensure_open(15), Err(BallotError::NotInProgress));
+
+		// The handler moves an upload on to its next stage or rejects it.
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(2),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(2),b"Doc2".to_vec(),vec![],None));
+		assert_ok!(<BhdaoModule as BallotHandler<VoteType, u64>>::on_finalized(VoteType::Qualification, 1, true));
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::VerificationVoteInProgress);
+		assert!(BhdaoModule::get_vote((VoteType::Verification,1)).is_some());
+		assert_ok!(<BhdaoModule as BallotHandler<VoteType, u64>>::on_finalized(VoteType::Qualification, 2, false));
+		assert_eq!(BhdaoModule::get_upload(2).unwrap().status, UploadStatus::Rejected);
+	});
+}
+
+#[test]
+fn it_counts_abstentions_and_vetoes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		for who in [2, 3, 4, 5] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		for who in [6, 7, 8] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+
+		assert_ok!(BhdaoModule::apply_for_expanded_role(RuntimeOrigin::signed(1),Roles::Verifier,None));
+		assert_eq!(Balances::reserved_balance(1), APPLICATION_DEPOSIT);
+
+		// Only Expert and council ballots can be vetoed.
+		run_to_block(200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(6),VoteType::CuratorVerification,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(7),VoteType::CuratorVerification,1,VoteChoice::Abstain));
+		assert_noop!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(8),VoteType::CuratorVerification,1,VoteChoice::NoWithVeto),Error::<Test>::VetoNotAllowed);
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(1),VoteType::CuratorVerification,1));
+		assert_eq!(BhdaoModule::get_vote((VoteType::CuratorVerification,1)).unwrap().status, VoteStatus::Passed);
+
+		// A majority of ayes still fails once vetoes reach a third of the ayes and nays.
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(2),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(3),VoteType::CuratorCouncilApproval,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(4),VoteType::CuratorCouncilApproval,1,VoteChoice::NoWithVeto));
+		assert_ok!(BhdaoModule::cast_vote_for_expanded_role(RuntimeOrigin::signed(5),VoteType::CuratorCouncilApproval,1,VoteChoice::Abstain));
+
+		let rationale = H256::repeat_byte(4);
+		assert_noop!(BhdaoModule::record_rationale(RuntimeOrigin::signed(6),VoteType::CuratorCouncilApproval,1,rationale),Error::<Test>::NotVoted);
+		assert_ok!(BhdaoModule::record_rationale(RuntimeOrigin::signed(4),VoteType::CuratorCouncilApproval,1,rationale));
+		assert_eq!(BhdaoModule::get_rationale((VoteType::CuratorCouncilApproval,1),4), Some(rationale));
+
+		let tally = Tally { yes_votes: 2, no_votes: 1, abstain_votes: 1, veto_votes: 1, yes_weight: 2, no_weight: 1, abstain_weight: 1, veto_weight: 1 };
+		assert_eq!(BhdaoModule::ballot_tally(VoteType::CuratorCouncilApproval,1), Some(tally));
+
+		run_to_block(2200);
+		assert_noop!(BhdaoModule::record_rationale(RuntimeOrigin::signed(5),VoteType::CuratorCouncilApproval,1,rationale),Error::<Test>::VotingWindowNotValid);
+		assert_ok!(BhdaoModule::finalize_vote_for_expanded_role(RuntimeOrigin::signed(1),VoteType::CuratorCouncilApproval,1));
+		System::assert_has_event(Event::VoteEnded { vote_type: VoteType::CuratorCouncilApproval, uid: 1, status: VoteStatus::Vetoed, tally }.into());
+		System::assert_last_event(Event::ApplicationDepositSlashed { uid: 1, who: 1, amount: APPLICATION_DEPOSIT }.into());
+		assert_eq!(BhdaoModule::get_member(1).unwrap().role, Roles::Contributor);
+		assert_eq!(Balances::total_balance(&1), INITIAL_BALANCE - APPLICATION_DEPOSIT);
+	});
+}
+
+#[test]
+fn it_slashes_the_deposit_of_vetoed_proposals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for who in [2, 3, 4] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),5,2,b"Contributor".to_vec()));
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(5),b"Doc1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::verify_upload(1));
+		assert!(BhdaoModule::allows_veto(VoteType::ExpertVerification));
+
+		assert_ok!(BhdaoModule::propose_member_action(RuntimeOrigin::signed(2),5,MemberAction::Remove,b"Spam".to_vec()));
+		assert_ok!(BhdaoModule::request_takedown(RuntimeOrigin::signed(5),1,H256::repeat_byte(1)));
+		assert_eq!(Balances::reserved_balance(5), TAKEDOWN_DEPOSIT);
+
+		// A takedown most Experts back still fails once a third of them veto it.
+		run_to_block(100);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Proposal,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Proposal,1,VoteChoice::NoWithVeto));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Proposal,1,VoteChoice::NoWithVeto));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(2),VoteType::Takedown,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(3),VoteType::Takedown,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Takedown,1,VoteChoice::NoWithVeto));
+
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(2),VoteType::Proposal,1));
+		System::assert_last_event(Event::MemberActionDepositSlashed { uid: 1, who: 2, amount: MEMBER_ACTION_DEPOSIT }.into());
+		assert_eq!(BhdaoModule::get_vote((VoteType::Proposal,1)).unwrap().status, VoteStatus::Vetoed);
+		assert!(BhdaoModule::get_member(5).is_some());
+		assert_eq!(Balances::total_balance(&2), INITIAL_BALANCE - MEMBER_ACTION_DEPOSIT);
+
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(2),VoteType::Takedown,1));
+		System::assert_has_event(Event::TakedownDepositSlashed { uid: 1, who: 5, amount: TAKEDOWN_DEPOSIT }.into());
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::Verified);
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
+
+#[test]
+fn it_amends_verified_uploads() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,2,b"Contributor2".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),3,2,b"Contributor3".to_vec()));
+		for who in [6, 7] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+
+		assert_ok!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Scan1".to_vec(),vec![],None,vec![(2,3000)]));
+		assert_ok!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(2),1));
+		assert_noop!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(2),1,b"Scan2".to_vec(),b"Better scan".to_vec()),Error::<Test>::NotVerified);
+		assert_ok!(BhdaoModule::verify_upload(1));
+		let token = BhdaoModule::get_upload_token(1).unwrap();
+
+		// Only the creator and co-contributors can amend an upload.
+		assert_noop!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(3),1,b"Scan2".to_vec(),b"Better scan".to_vec()),Error::<Test>::NotUploadContributor);
+		assert_ok!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(2),1,b"Scan2".to_vec(),b"Better scan".to_vec()));
+		System::assert_has_event(Event::AmendmentSubmitted { uid: 1, upload_id: 1, who: 2 }.into());
+		assert_eq!(Balances::reserved_balance(2), AMENDMENT_DEPOSIT);
+		assert_noop!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(1),1,b"Scan3".to_vec(),vec![]),Error::<Test>::AmendmentPending);
+
+		// The gateway check of the proposed content is keyed by the amended upload.
+		let (check, signature) = signed_check(1, true, true);
+		assert_ok!(BhdaoModule::submit_amendment_attestation(RuntimeOrigin::none(),check.clone(),signature.clone()));
+		System::assert_last_event(Event::AmendmentAttested { uid: 1, upload_id: 1, available: true, intact: true }.into());
+		assert_noop!(BhdaoModule::submit_amendment_attestation(RuntimeOrigin::none(),check,signature),Error::<Test>::ContentAlreadyAttested);
+
+		run_to_block(200);
+		assert_noop!(BhdaoModule::cast_vote(RuntimeOrigin::signed(1),VoteType::Amendment,1,VoteChoice::Aye),Error::<Test>::NotEligibleToVerify);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(6),VoteType::Amendment,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(7),VoteType::Amendment,1,VoteChoice::Aye));
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(6),VoteType::Amendment,1));
+
+		System::assert_has_event(pallet_nft::Event::TokenURIUpdated { id: token, uri: b"Scan2".to_vec() }.into());
+		System::assert_last_event(Event::UploadAmended { uid: 1, amendment_id: 1, version: 2 }.into());
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().hash, b"Scan2".to_vec());
+		assert_eq!(NftModule::get_token_uri(token), Some(b"Scan2".to_vec()));
+		let versions = BhdaoModule::get_upload_versions(1);
+		assert_eq!(versions.iter().map(|version| (version.hash.clone(), version.amendment)).collect::<Vec<_>>(),
+			vec![(b"Scan1".to_vec(), None), (b"Scan2".to_vec(), Some(1))]);
+		assert_eq!(BhdaoModule::get_pending_amendment(1), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// Ayes don't pass an amendment whose content was never attested. The amendment fails,
+		// leaving the canonical version alone, and the deposit is slashed.
+		assert_ok!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(1),1,b"Scan3".to_vec(),vec![]));
+		run_to_block(1200);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(6),VoteType::Amendment,2,VoteChoice::Aye));
+		run_to_block(2200);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(6),VoteType::Amendment,2));
+		System::assert_has_event(Event::AmendmentDepositSlashed { uid: 2, who: 1, amount: AMENDMENT_DEPOSIT }.into());
+		System::assert_last_event(Event::AmendmentRejected { uid: 2, upload_id: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().hash, b"Scan2".to_vec());
+		assert_eq!(BhdaoModule::get_upload_versions(1).len(), 2);
+	});
+}
+
+#[test]
+fn it_reviews_collections_as_one_unit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),2,2,b"Contributor2".to_vec()));
+		for who in [6, 7] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,3,b"Verifier".to_vec()));
+		}
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),9,4,b"Expert1".to_vec()));
+
+		assert_ok!(BhdaoModule::create_collection(RuntimeOrigin::signed(1),b"Family letters".to_vec()));
+		System::assert_last_event(Event::CollectionCreated { uid: 1, curator: 1 }.into());
+		assert_noop!(BhdaoModule::submit_collection(RuntimeOrigin::signed(1),1),Error::<Test>::NothingToReview);
+		assert_ok!(BhdaoModule::upload_to_collection(RuntimeOrigin::signed(1),1,b"Letter1".to_vec(),vec![],None));
+		assert_ok!(BhdaoModule::upload_to_collection(RuntimeOrigin::signed(1),1,b"Letter2".to_vec(),vec![],None));
+		assert_noop!(BhdaoModule::upload_to_collection(RuntimeOrigin::signed(2),1,b"Letter3".to_vec(),vec![],None),Error::<Test>::NotCurator);
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::InCollection);
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)), None);
+
+		// Only the curator's own uploads and Verified ones their creator consented to can join.
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(2),b"Doc3".to_vec(),vec![],None));
+		assert_noop!(BhdaoModule::add_to_collection(RuntimeOrigin::signed(1),1,3),Error::<Test>::CannotCollectUpload);
+		// Nor can uploads that left review, even the curator's own.
+		assert_ok!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Letter4".to_vec(),vec![],None,vec![(2,3000)]));
+		assert_ok!(BhdaoModule::decline_co_contribution(RuntimeOrigin::signed(2),4));
+		assert_noop!(BhdaoModule::add_to_collection(RuntimeOrigin::signed(1),1,4),Error::<Test>::UploadClosed);
+
+		assert_noop!(BhdaoModule::reorder_collection(RuntimeOrigin::signed(1),1,vec![2]),Error::<Test>::InvalidOrder);
+		assert_ok!(BhdaoModule::reorder_collection(RuntimeOrigin::signed(1),1,vec![2, 1]));
+		assert_eq!(BhdaoModule::get_collection(1).unwrap().uploads, vec![2, 1]);
+
+		assert_ok!(BhdaoModule::submit_collection(RuntimeOrigin::signed(1),1));
+		assert_noop!(BhdaoModule::upload_to_collection(RuntimeOrigin::signed(1),1,b"Letter3".to_vec(),vec![],None),Error::<Test>::CollectionUnderReview);
+		run_to_block(200);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(6),VoteType::CollectionReview,1,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(7),VoteType::CollectionReview,1,VoteChoice::Aye));
+		run_to_block(1100);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(6),VoteType::CollectionReview,1));
+		System::assert_last_event(Event::CollectionReviewed { uid: 1, passed: true }.into());
+
+		// Each upload still goes through its own expert review.
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::UnderExpertReview);
+		assert_eq!(BhdaoModule::get_expert_review(2).unwrap().panel, vec![9]);
+		run_to_block(2200);
+		for upload_id in [1, 2] {
+			assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(6),upload_id));
+		}
+
+		// Every upload was Verified and its token grouped under the pallet_nft collection.
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::Verified);
+		assert_eq!(BhdaoModule::get_upload(2).unwrap().status, UploadStatus::Verified);
+		assert_eq!(BhdaoModule::get_collection(1).unwrap().status, CollectionStatus::Open);
+		let mut tokens = BhdaoModule::collection_tokens(1);
+		tokens.sort();
+		let mut expected = vec![BhdaoModule::get_upload_token(1).unwrap(), BhdaoModule::get_upload_token(2).unwrap()];
+		expected.sort();
+		assert_eq!(tokens, expected);
+		assert_eq!(NftModule::get_token_collection(expected[0]), Some(1));
+
+		// Another contributor's Verified upload joins once they consent.
+		assert_ok!(BhdaoModule::verify_upload(3));
+		assert_noop!(BhdaoModule::add_to_collection(RuntimeOrigin::signed(1),1,3),Error::<Test>::CannotCollectUpload);
+		assert_noop!(BhdaoModule::consent_to_collection(RuntimeOrigin::signed(1),3,1),Error::<Test>::NotUploadCreator);
+		assert_ok!(BhdaoModule::consent_to_collection(RuntimeOrigin::signed(2),3,1));
+		assert_ok!(BhdaoModule::add_to_collection(RuntimeOrigin::signed(1),1,3));
+		assert_eq!(BhdaoModule::get_collection(1).unwrap().uploads, vec![2, 1, 3]);
+	});
+}
+
+#[test]
+fn it_splits_co_contributed_uploads() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+
+		assert_noop!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Interview".to_vec(),vec![],None,vec![(1,3000)]),Error::<Test>::InvalidCoContributors);
+		assert_noop!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Interview".to_vec(),vec![],None,vec![(2,6000),(3,4000)]),Error::<Test>::InvalidCoContributors);
+		assert_ok!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Interview".to_vec(),vec![],None,vec![(2,3000),(3,1000)]));
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::AwaitingCoContributors);
+
+		assert_noop!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(4),1),Error::<Test>::NotACoContributor);
+		assert_ok!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(2),1));
+		assert_eq!(BhdaoModule::get_vote((VoteType::Qualification,1)), None);
+		assert_ok!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(3),1));
+		System::assert_last_event(Event::NewVote { vote_type: VoteType::Qualification, uid: 1 }.into());
+		assert_eq!(BhdaoModule::get_upload(1).unwrap().status, UploadStatus::QualificationVoteInProgress);
+		assert_noop!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(2),1),Error::<Test>::NotAwaitingCoContributors);
+
+		assert_ok!(BhdaoModule::verify_upload(1));
+		let token = BhdaoModule::get_upload_token(1).unwrap();
+		assert_eq!((NftModule::balance_of(token,1), NftModule::balance_of(token,2), NftModule::balance_of(token,3)), (54, 27, 9));
+		assert_eq!(NftModule::get_total_supply(token), Some(90));
+		assert_eq!(NftModule::royalty_split(token), Some(vec![(1,6000),(2,3000),(3,1000)]));
+
+		// A co-contributor who declines withdraws the upload, and so can the creator.
+		assert_ok!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Interview2".to_vec(),vec![],None,vec![(2,3000)]));
+		assert_noop!(BhdaoModule::decline_co_contribution(RuntimeOrigin::signed(3),2),Error::<Test>::NotACoContributor);
+		assert_ok!(BhdaoModule::decline_co_contribution(RuntimeOrigin::signed(2),2));
+		System::assert_last_event(Event::UploadWithdrawn { uid: 2 }.into());
+		assert_eq!(BhdaoModule::get_upload(2).unwrap().status, UploadStatus::Withdrawn);
+		assert_noop!(BhdaoModule::accept_co_contribution(RuntimeOrigin::signed(2),2),Error::<Test>::NotAwaitingCoContributors);
+
+		assert_ok!(BhdaoModule::upload_with_co_contributors(RuntimeOrigin::signed(1),b"Interview3".to_vec(),vec![],None,vec![(2,3000)]));
+		assert_noop!(BhdaoModule::cancel_co_contributed_upload(RuntimeOrigin::signed(2),3),Error::<Test>::NotUploadCreator);
+		assert_ok!(BhdaoModule::cancel_co_contributed_upload(RuntimeOrigin::signed(1),3));
+		assert_eq!(BhdaoModule::get_upload(3).unwrap().status, UploadStatus::Withdrawn);
+		assert!(BhdaoModule::get_co_contributors(3).is_empty());
+	});
+}
+
+#[test]
+fn it_takes_down_sensitive_uploads() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		for who in [4, 5] {
+			assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),who,4,b"Expert".to_vec()));
+		}
+
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],None));
+		let (check, signature) = signed_check(1, true, true);
+		assert_ok!(BhdaoModule::submit_content_attestation(RuntimeOrigin::none(),check,signature));
+		assert_noop!(BhdaoModule::mark_sensitive(RuntimeOrigin::signed(4),1,vec![8]),Error::<Test>::NotUploadCreator);
+		assert_ok!(BhdaoModule::mark_sensitive(RuntimeOrigin::signed(1),1,vec![8]));
+		// Subjects can be added but not dropped.
+		assert_ok!(BhdaoModule::mark_sensitive(RuntimeOrigin::signed(1),1,vec![]));
+		assert_eq!(BhdaoModule::get_subjects(1), Some(vec![8]));
+		assert_ok!(BhdaoModule::start_expert_review(1));
+
+		// The named subject has to consent before the upload can be Verified.
+		run_to_block(1100);
+		assert_noop!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1),Error::<Test>::MissingConsent);
+		assert_noop!(BhdaoModule::attest_consent(RuntimeOrigin::signed(9),1,H256::repeat_byte(1)),Error::<Test>::NotASubject);
+		assert_ok!(BhdaoModule::attest_consent(RuntimeOrigin::signed(8),1,H256::repeat_byte(1)));
+		assert_ok!(BhdaoModule::finalize_expert_review(RuntimeOrigin::signed(1),1));
+		let token = BhdaoModule::get_upload_token(1).unwrap();
+		assert_noop!(BhdaoModule::mark_sensitive(RuntimeOrigin::signed(1),1,vec![9]),Error::<Test>::UploadClosed);
+
+		// An amendment that never passed still names content the redaction has to clear.
+		assert_ok!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(1),1,b"Doc2".to_vec(),b"Retake".to_vec()));
+		run_to_block(2200);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(4),VoteType::Amendment,1));
+
+		// Subjects can ask for a takedown without being members. A rejected takedown leaves a
+		// freeze it didn't place alone.
+		assert_ok!(NftModule::freeze_token(RuntimeOrigin::root(),token));
+		assert_noop!(BhdaoModule::request_takedown(RuntimeOrigin::signed(9),1,H256::repeat_byte(2)),Error::<Test>::NotAMember);
+		assert_ok!(BhdaoModule::request_takedown(RuntimeOrigin::signed(8),1,H256::repeat_byte(2)));
+		assert_noop!(BhdaoModule::submit_amendment(RuntimeOrigin::signed(1),1,b"Doc2".to_vec(),vec![]),Error::<Test>::TakedownPending);
+		run_to_block(3300);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(4),VoteType::Takedown,1));
+		System::assert_last_event(Event::TakedownRejected { uid: 1, upload_id: 1 }.into());
+		assert!(NftModule::is_frozen(token));
+
+		// The upload can't be put to another takedown until the cooldown is over.
+		assert_noop!(BhdaoModule::request_takedown(RuntimeOrigin::signed(8),1,H256::repeat_byte(2)),Error::<Test>::TakedownCoolingDown);
+		assert_ok!(NftModule::thaw_token(RuntimeOrigin::root(),token));
+		run_to_block(3300 + TAKEDOWN_COOLDOWN as u64);
+		assert_ok!(BhdaoModule::request_takedown(RuntimeOrigin::signed(8),1,H256::repeat_byte(2)));
+		assert!(NftModule::is_frozen(token));
+
+		run_to_block(3900);
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(4),VoteType::Takedown,2,VoteChoice::Aye));
+		assert_ok!(BhdaoModule::cast_vote(RuntimeOrigin::signed(5),VoteType::Takedown,2,VoteChoice::Aye));
+		run_to_block(4900);
+		assert_ok!(BhdaoModule::finalize_vote(RuntimeOrigin::signed(4),VoteType::Takedown,2));
+
+		let tombstone = BlakeTwo256::hash(b"Doc1");
+		System::assert_last_event(Event::UploadRedacted { uid: 1, takedown_id: 2, tombstone }.into());
+		assert_eq!(BhdaoModule::get_upload(1), Some(Upload{creator: 1, hash: vec![], status: UploadStatus::Redacted, categories: vec![]}));
+		assert_eq!(BhdaoModule::get_tombstone(1), Some(tombstone));
+		assert!(BhdaoModule::get_upload_versions(1).is_empty());
+		assert_eq!(BhdaoModule::get_consent(1,8), None);
+		assert_eq!(BhdaoModule::get_amendment(1).map(|amendment| (amendment.hash, amendment.reason)), Some((vec![], vec![])));
+		assert_eq!(BhdaoModule::get_content_attestation(1), None);
+		assert_eq!(NftModule::get_token_uri(token), Some(vec![]));
+		assert!(NftModule::is_frozen(token));
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(1),1,2,token,1),pallet_nft::Error::<Test>::TokenIsFrozen);
+		assert_noop!(BhdaoModule::propose_license_change(RuntimeOrigin::signed(1),1,1),Error::<Test>::UploadIsRedacted);
+	});
+}
+
+#[test]
+fn it_licenses_uploads_and_relicenses_with_holder_consent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(BhdaoModule::set_membership(RuntimeOrigin::root(),1,2,b"Contributor1".to_vec()));
+		assert_noop!(BhdaoModule::register_license(RuntimeOrigin::signed(1),1,License::CcBy),sp_runtime::DispatchError::BadOrigin);
+		assert_ok!(BhdaoModule::register_license(RuntimeOrigin::root(),1,License::CcBy));
+		assert_ok!(BhdaoModule::register_license(RuntimeOrigin::root(),2,License::Custom(H256::repeat_byte(7))));
+		assert_noop!(BhdaoModule::register_license(RuntimeOrigin::root(),2,License::Cc0),Error::<Test>::LicenseAlreadyRegistered);
+
+		assert_noop!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],Some(3)),Error::<Test>::UnknownLicense);
+		assert_ok!(BhdaoModule::upload_document(RuntimeOrigin::signed(1),b"Doc1".to_vec(),vec![],Some(1)));
+		assert_ok!(BhdaoModule::verify_upload(1));
+		let token = BhdaoModule::get_upload_token(1).unwrap();
+		assert_eq!(BhdaoModule::upload_license(1), Some(License::CcBy));
+		assert_eq!(BhdaoModule::token_license(token), Some(License::CcBy));
+
+		// Only holders can ask, and only for licenses still in the registry.
+		assert_ok!(BhdaoModule::retire_license(RuntimeOrigin::root(),1));
+		assert_noop!(BhdaoModule::propose_license_change(RuntimeOrigin::signed(1),1,1),Error::<Test>::UnknownLicense);
+		assert_noop!(BhdaoModule::propose_license_change(RuntimeOrigin::signed(9),1,2),pallet_nft::Error::<Test>::NotATokenHolder);
+		assert_ok!(BhdaoModule::propose_license_change(RuntimeOrigin::signed(1),1,2));
+		System::assert_last_event(Event::LicenseChangeProposed { uid: 1, proposal_id: 1, license: 2 }.into());
+		assert_eq!(NftModule::get_holder_proposal(1).unwrap().terms, BlakeTwo256::hash_of(&License::<H256>::Custom(H256::repeat_byte(7))));
+
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(1),1,true));
+		assert_noop!(BhdaoModule::apply_license_change(RuntimeOrigin::signed(1),1),pallet_nft::Error::<Test>::ProposalStillOpen);
+		run_to_block(11);
+		assert_ok!(BhdaoModule::apply_license_change(RuntimeOrigin::signed(1),1));
+		System::assert_last_event(Event::UploadLicenseChanged { uid: 1, license: 2 }.into());
+		assert_eq!(BhdaoModule::token_license(token), Some(License::Custom(H256::repeat_byte(7))));
+		assert_noop!(BhdaoModule::apply_license_change(RuntimeOrigin::signed(1),1),Error::<Test>::LicenseChangeNotFound);
+
+		// A license retired during the vote is not applied.
+		assert_ok!(BhdaoModule::register_license(RuntimeOrigin::root(),3,License::Cc0));
+		assert_ok!(BhdaoModule::propose_license_change(RuntimeOrigin::signed(1),1,3));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(1),2,true));
+		assert_ok!(BhdaoModule::retire_license(RuntimeOrigin::root(),3));
+		run_to_block(21);
+		assert_ok!(BhdaoModule::apply_license_change(RuntimeOrigin::signed(1),2));
+		System::assert_last_event(Event::LicenseChangeRejected { uid: 1, proposal_id: 2 }.into());
+		assert_eq!(BhdaoModule::token_license(token), Some(License::Custom(H256::repeat_byte(7))));
+	});
+}
+
+#[test]
+fn it_migrates_votes_to_weighted_tallies() {
+	use crate::migrations::v1;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<BhdaoModule>();
+		let old = v1::OldVote::<u64> { yes_votes: 3, no_votes: 1, start: 1, end: 10, status: VoteStatus::InProgress };
+		frame_support::storage::unhashed::put(&v1::Votes::<Test>::hashed_key_for((VoteType::Qualification, 1)), &old);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		let vote = v1::Votes::<Test>::get((VoteType::Qualification, 1)).unwrap();
+		assert_eq!((vote.yes_votes, vote.no_votes, vote.yes_weight, vote.no_weight), (3, 1, 3, 1));
+		assert_eq!(BhdaoModule::on_chain_storage_version(), 1);
+
+		// Running it again is a no-op.
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(v1::Votes::<Test>::get((VoteType::Qualification, 1)).unwrap().yes_weight, 3);
+	});
+}
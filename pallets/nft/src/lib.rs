@@ -2,6 +2,18 @@
 
 pub use pallet::*;
 
+/// Rules from outside this pallet on who can acquire a token, checked on every transfer.
+pub trait TransferFilter<AccountId, TokenId> {
+	/// Whether `to` may receive token `id`.
+	fn can_receive(to: &AccountId, id: TokenId) -> bool;
+}
+
+impl<AccountId, TokenId> TransferFilter<AccountId, TokenId> for () {
+	fn can_receive(_to: &AccountId, _id: TokenId) -> bool {
+		true
+	}
+}
+
 #[cfg(test)]
 mod mock;
 
@@ -17,10 +29,9 @@ pub mod pallet {
 
 	use sp_runtime::{
 		traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize,Zero,CheckedAdd,CheckedSub}
-		,ArithmeticError,FixedPointOperand,};
+		,ArithmeticError,FixedPointOperand,Perbill,SaturatedConversion,};
 	use sp_std::vec::Vec;
 	use sp_std::{fmt::Debug,cmp::{Eq, PartialEq}};
-	use sp_runtime::SaturatedConversion;
 
 	#[cfg(feature = "std")]
 	use frame_support::serde::{Deserialize, Serialize};
@@ -55,6 +66,59 @@ pub mod pallet {
 			+ MaxEncodedLen
 			+ TypeInfo
 			+ FixedPointOperand;
+		/// Origin allowed to mint tokens with `mint` and `mint_batch`. Other pallets mint through
+		/// `_mint_batch` without it.
+		type MintOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to freeze and thaw tokens, holdings and collections.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Blocks holders of a token can vote on a proposal for it.
+		#[pallet::constant]
+		type ProposalPeriod: Get<u32>;
+		/// Most proposals open at once for one token.
+		#[pallet::constant]
+		type MaxOpenProposals: Get<u32>;
+		/// Share of a token's supply a holder needs to put a proposal to its holders.
+		#[pallet::constant]
+		type MinProposalStake: Get<Perbill>;
+		/// Most snapshots and votes of closed proposals removed in one block.
+		#[pallet::constant]
+		type MaxProposalCleanup: Get<u32>;
+		/// Who can receive a token, such as early access windows kept by another pallet.
+		type TransferFilter: crate::TransferFilter<Self::AccountId, Self::TokenId>;
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ProposalKind {
+		Licensing,
+		ExhibitionLoan,
+		/// Sale of the physical artifact behind the token.
+		ArtifactSale,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, Copy)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ProposalStatus {
+		Open,
+		Passed,
+		Failed,
+	}
+
+	/// A proposal put to the holders of one token, who vote with their balance of it.
+	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct HolderProposal<T:Config> {
+		pub token: T::TokenId,
+		pub proposer: T::AccountId,
+		pub kind: ProposalKind,
+		/// Hash of the proposed terms.
+		pub terms: T::Hash,
+		pub end: T::BlockNumber,
+		/// Supply of the token when the proposal was made.
+		pub supply: T::Balance,
+		pub ayes: T::Balance,
+		pub nays: T::Balance,
+		pub status: ProposalStatus,
 	}
 
 	#[pallet::storage]
@@ -87,6 +151,38 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub(super) type FrozenTokens<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_holding_frozen)]
+	pub(super) type FrozenHoldings<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_collection_frozen)]
+	pub(super) type FrozenCollections<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		bool,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn operator_approvals)]
 	pub(super) type OperatorApprovals<T:Config> = StorageDoubleMap<
@@ -131,12 +227,125 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_collection_owner)]
+	pub(super) type Collections<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_token_collection)]
+	pub(super) type TokenCollection<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		u64,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_tokens)]
+	pub(super) type CollectionTokens<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::TokenId,
+		bool,
+		OptionQuery,
+	>;
+
+	/// Id of the license a token is published under, from the registry of the pallet minting it.
+	#[pallet::storage]
+	#[pallet::getter(fn get_token_license)]
+	pub(super) type TokenLicense<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		u32,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn holder_proposal_count)]
+	pub(super) type HolderProposalCount<T> = StorageValue<_, u64,ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_holder_proposal)]
+	pub(super) type HolderProposals<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		HolderProposal<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn open_proposals)]
+	pub(super) type OpenProposals<T:Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		Vec<u64>,
+		ValueQuery,
+	>;
+
+	/// Balance of a holder when a proposal was made, recorded just before it first changes
+	/// while the proposal is open.
+	#[pallet::storage]
+	#[pallet::getter(fn get_snapshot)]
+	pub(super) type Snapshots<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn has_voted)]
+	pub(super) type HolderVotes<T:Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Closed proposals whose snapshots and votes are still to be removed, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_cleanups)]
+	pub(super) type PendingCleanups<T> = StorageValue<_, Vec<u64>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		TokenMinted{to: T::AccountId,id: T::TokenId,amount: T::Balance},
 		ApprovalForAll{owner: T::AccountId,operator: T::AccountId,approved: bool},
 		TokenTransferred{from: T::AccountId, to: T::AccountId,id: T::TokenId, amount: T::Balance},
+		TokenURIUpdated{id: T::TokenId, uri: Vec<u8>},
+		CollectionCreated{id: u64, owner: T::AccountId},
+		TokenAddedToCollection{id: u64, token: T::TokenId},
+		RoyaltySplitSet{id: T::TokenId, royalty_split: Vec<(T::AccountId,u16)>},
+		TokenFrozen{id: T::TokenId},
+		TokenThawed{id: T::TokenId},
+		HoldingFrozen{id: T::TokenId, who: T::AccountId},
+		HoldingThawed{id: T::TokenId, who: T::AccountId},
+		CollectionFrozen{id: u64},
+		CollectionThawed{id: u64},
+		BatchTransferred{from: T::AccountId, to: T::AccountId, ids: Vec<T::TokenId>, amounts: Vec<T::Balance>},
+		HolderProposalCreated{id: u64, token: T::TokenId, kind: ProposalKind, proposer: T::AccountId},
+		HolderVoted{id: u64, who: T::AccountId, aye: bool, weight: T::Balance},
+		HolderProposalClosed{id: u64, status: ProposalStatus},
+		TokenLicenseSet{id: T::TokenId, license: u32},
 	}
 
 	// Errors inform users that something went wrong.
@@ -162,6 +371,53 @@ pub mod pallet {
 		RoyaltiesShouldSumUpTo10000,
 		/// Different Lengths of Input Vectors
 		DifferentLengthInputVectors,
+		/// Collection Does Not Exist
+		CollectionDoesNotExist,
+		/// Collection Already Exists
+		CollectionAlreadyExists,
+		/// Token Already In A Collection
+		TokenAlreadyInCollection,
+		/// Token Is Frozen
+		TokenIsFrozen,
+		/// Holding Is Frozen
+		HoldingIsFrozen,
+		/// Collection Is Frozen
+		CollectionIsFrozen,
+		/// Not Frozen
+		NotFrozen,
+		/// Not A Token Holder
+		NotATokenHolder,
+		/// Proposal Not Found
+		ProposalNotFound,
+		/// Proposal Voting Ended
+		ProposalVotingEnded,
+		/// Proposal Still Open
+		ProposalStillOpen,
+		/// Proposal Already Closed
+		ProposalAlreadyClosed,
+		/// Already Voted
+		AlreadyVoted,
+		/// Too Many Open Proposals
+		TooManyOpenProposals,
+		/// Recipient Not Allowed
+		RecipientNotAllowed,
+		/// Proposer Stake Too Low
+		ProposerStakeTooLow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let per_key = T::DbWeight::get().writes(1);
+			let base = T::DbWeight::get().reads_writes(1,1);
+			if Self::get_pending_cleanups().is_empty() || remaining_weight.any_lt(base.saturating_add(per_key)) {
+				return T::DbWeight::get().reads(1)
+			}
+			let limit = (remaining_weight.ref_time().saturating_sub(base.ref_time()) / per_key.ref_time().max(1))
+				.min(T::MaxProposalCleanup::get().into()).saturated_into::<u32>();
+			let removed = Self::clean_up_proposals(limit);
+			base.saturating_add(per_key.saturating_mul(removed.into()))
+		}
 	}
 
 
@@ -170,45 +426,19 @@ pub mod pallet {
 		#[pallet::call_index(0)]
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,4).ref_time())]
 		pub fn mint(origin: OriginFor<T>,to: T::AccountId,id: T::TokenId,amount: T::Balance,uri: Vec<u8>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			ensure!(!Self::token_exists(id),Error::<T>::TokenAlreadyExists);
-			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			T::MintOrigin::ensure_origin(origin)?;
 			//, royalty_split: Vec<(T::AccountId,u16)>
 			//Self::check_royalty_split(royalty_split.clone())?;
 			//ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAddress);
-			let tokens_count = Self::get_tokens_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
-
-			Self::_mint(who.clone(),to,id,amount);
-
-			TotalSupply::<T>::insert(id,amount);
-			TokenURI::<T>::insert(id,uri);
-			TokensCount::<T>::put(tokens_count);
-			
-			Ok(())
+			Self::_mint_batch(sp_std::vec![to],id,sp_std::vec![amount],uri)
 		}
 
 		
 		#[pallet::call_index(2)]
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,4).ref_time())]
 		pub fn mint_batch(origin: OriginFor<T>,tos: Vec<T::AccountId>, id: T::TokenId, amounts: Vec<T::Balance>,uri: Vec<u8>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			ensure!(!Self::token_exists(id),Error::<T>::TokenAlreadyExists);
-			ensure!(tos.len() == amounts.len(),Error::<T>::DifferentLengthInputVectors);
-			let tokens_count = Self::get_tokens_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
-			let mut total_amount: u128 = 0;
-
-
-			for (to, amount) in tos.iter().zip(amounts) {
-				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
-				total_amount += amount.saturated_into::<u128>();
-				Self::_mint(who.clone(), to.clone(), id, amount)?;
-			}
-
-			//TotalSupply::<T>::insert(id,total_amount);
-			TokenURI::<T>::insert(id,uri);
-			TokensCount::<T>::put(tokens_count);
-
-			Ok(())
+			T::MintOrigin::ensure_origin(origin)?;
+			Self::_mint_batch(tos,id,amounts,uri)
 		}
 
 		#[pallet::call_index(3)]
@@ -218,7 +448,7 @@ pub mod pallet {
 			ensure!(who.clone() == from.clone() || Self::operator_approvals(from.clone(),who.clone()),Error::<T>::NotAllowedToTransfer);
 			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
 			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
-			ensure!(to != who, Error::<T>::SameAddress);
+			ensure!(to != from, Error::<T>::SameAddress);
 
 			Self::_transfer(from.clone(),to.clone(),id,amount)?;
 			Self::deposit_event(Event::TokenTransferred{from: from, to: to,id: id, amount: amount});
@@ -236,6 +466,117 @@ pub mod pallet {
 			Ok(())
 		}
 
+		#[pallet::call_index(5)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2).ref_time())]
+		pub fn batch_transfer(origin: OriginFor<T>,from: T::AccountId,to: T::AccountId,ids: Vec<T::TokenId>,amounts: Vec<T::Balance>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who.clone() == from.clone() || Self::operator_approvals(from.clone(),who.clone()),Error::<T>::NotAllowedToTransfer);
+			ensure!(ids.len() == amounts.len(),Error::<T>::DifferentLengthInputVectors);
+			ensure!(to != from, Error::<T>::SameAddress);
+
+			Self::_batch_transfer_from(from.clone(),to.clone(),ids.clone(),amounts.clone())?;
+			Self::deposit_event(Event::BatchTransferred{from,to,ids,amounts});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn freeze_token(origin: OriginFor<T>, id: T::TokenId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::_freeze_token(id)
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn thaw_token(origin: OriginFor<T>, id: T::TokenId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::_thaw_token(id)
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn freeze_holding(origin: OriginFor<T>, id: T::TokenId, who: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+			ensure!(Balances::<T>::contains_key(id,&who),Error::<T>::NotATokenHolder);
+			FrozenHoldings::<T>::insert(id,who.clone(),true);
+			Self::deposit_event(Event::HoldingFrozen { id, who });
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn thaw_holding(origin: OriginFor<T>, id: T::TokenId, who: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_holding_frozen(id,who.clone()),Error::<T>::NotFrozen);
+			FrozenHoldings::<T>::remove(id,who.clone());
+			Self::deposit_event(Event::HoldingThawed { id, who });
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn freeze_collection(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(Collections::<T>::contains_key(id),Error::<T>::CollectionDoesNotExist);
+			FrozenCollections::<T>::insert(id,true);
+			Self::deposit_event(Event::CollectionFrozen { id });
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn thaw_collection(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_collection_frozen(id),Error::<T>::NotFrozen);
+			FrozenCollections::<T>::remove(id);
+			Self::deposit_event(Event::CollectionThawed { id });
+			Ok(())
+		}
+
+		/// Put a proposal to the holders of `token`. Only holders of at least `MinProposalStake`
+		/// of its supply can propose.
+		#[pallet::call_index(12)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,3).ref_time())]
+		pub fn propose(origin: OriginFor<T>, token: T::TokenId, kind: ProposalKind, terms: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::_propose(who, token, kind, terms)?;
+			Ok(())
+		}
+
+		/// Vote on a proposal with the balance held when it was made.
+		#[pallet::call_index(13)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2).ref_time())]
+		pub fn vote(origin: OriginFor<T>, id: u64, aye: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut proposal = Self::get_holder_proposal(id).ok_or(Error::<T>::ProposalNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(proposal.status == ProposalStatus::Open && now < proposal.end,Error::<T>::ProposalVotingEnded);
+			ensure!(!Self::has_voted(id,who.clone()),Error::<T>::AlreadyVoted);
+
+			let weight = Self::balance_at(id,proposal.token,&who);
+			ensure!(!weight.is_zero(),Error::<T>::NotATokenHolder);
+			match aye {
+				true => proposal.ayes = proposal.ayes.checked_add(&weight).ok_or(ArithmeticError::Overflow)?,
+				false => proposal.nays = proposal.nays.checked_add(&weight).ok_or(ArithmeticError::Overflow)?,
+			};
+			HolderProposals::<T>::insert(id,proposal);
+			HolderVotes::<T>::insert(id,who.clone(),true);
+			Self::deposit_event(Event::HolderVoted { id, who, aye, weight });
+			Ok(())
+		}
+
+		/// Close a proposal once voting ended. It passes with ayes from holders of more than half
+		/// the supply.
+		#[pallet::call_index(14)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2).ref_time())]
+		pub fn close_proposal(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::_close_proposal(id)?;
+			Ok(())
+		}
+
 	}
 
 	// Helpful functions
@@ -245,7 +586,22 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Fails if `id` or `from`'s holding of it is frozen, directly or through its collection.
+		/// Every transfer goes through `_transfer` and this check.
+		pub fn ensure_transferable(id: T::TokenId, from: &T::AccountId) -> DispatchResult {
+			ensure!(!Self::is_frozen(id),Error::<T>::TokenIsFrozen);
+			ensure!(!Self::is_holding_frozen(id,from.clone()),Error::<T>::HoldingIsFrozen);
+			if let Some(collection) = Self::get_token_collection(id) {
+				ensure!(!Self::is_collection_frozen(collection),Error::<T>::CollectionIsFrozen);
+			}
+			Ok(())
+		}
+
 		pub fn _transfer(from: T::AccountId, to: T::AccountId, id: T::TokenId, amount: T::Balance) -> DispatchResult {
+			Self::ensure_transferable(id,&from)?;
+			ensure!(T::TransferFilter::can_receive(&to,id),Error::<T>::RecipientNotAllowed);
+			Self::snapshot(id,&from);
+			Self::snapshot(id,&to);
 			Balances::<T>::try_mutate(id.clone(),from.clone(),|balance|-> Result<(), Error<T>> {
 				let from_balance =
 					balance.checked_sub(&amount).ok_or(Error::<T>::InsufficientBalanceForTransfer)?;
@@ -262,20 +618,190 @@ pub mod pallet {
 		}
 
 		pub fn _batch_transfer_from(from: T::AccountId, to: T::AccountId, ids: Vec<T::TokenId>, amounts: Vec<T::Balance>) -> DispatchResult {
+			for (id, amount) in ids.into_iter().zip(amounts) {
+				ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+				Self::_transfer(from.clone(),to.clone(),id,amount)?;
+			}
+			Ok(())
+		}
+
+		/// Mint a new token `id` to `tos`, each receiving the matching amount.
+		pub fn _mint_batch(tos: Vec<T::AccountId>, id: T::TokenId, amounts: Vec<T::Balance>, uri: Vec<u8>) -> DispatchResult {
+			ensure!(!Self::token_exists(id),Error::<T>::TokenAlreadyExists);
+			ensure!(tos.len() == amounts.len(),Error::<T>::DifferentLengthInputVectors);
+			let tokens_count = Self::get_tokens_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let mut total_amount: T::Balance = Zero::zero();
+
+			for (to, amount) in tos.iter().zip(amounts) {
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+				total_amount = total_amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+				Self::_mint(to.clone(), id, amount)?;
+			}
+
+			TotalSupply::<T>::insert(id,total_amount);
+			TokenURI::<T>::insert(id,uri);
+			TokensCount::<T>::put(tokens_count);
 
 			Ok(())
 		}
 
-		pub fn _mint(from: T::AccountId, to: T::AccountId,id: T::TokenId, amount: T::Balance) -> DispatchResult {
+		pub fn _mint(to: T::AccountId,id: T::TokenId, amount: T::Balance) -> DispatchResult {
 			Balances::<T>::insert(id,to.clone(),amount);
 			Self::deposit_event(Event::TokenMinted { to, id, amount });		
 			Ok(())
 		}
 
-		pub fn _mint_batch(to: T::AccountId) -> DispatchResult {
+		/// Record `who`'s balance of `id` for every open proposal on it that has no snapshot of
+		/// them yet. Called before the balance changes.
+		fn snapshot(id: T::TokenId, who: &T::AccountId) {
+			for proposal in Self::open_proposals(id) {
+				if !Snapshots::<T>::contains_key(proposal,who) {
+					Snapshots::<T>::insert(proposal,who,Self::balance_of(id,who.clone()));
+				}
+			}
+		}
+
+		/// Balance of `who` in `token` when proposal `id` was made.
+		pub fn balance_at(id: u64, token: T::TokenId, who: &T::AccountId) -> T::Balance {
+			Self::get_snapshot(id,who).unwrap_or_else(|| Self::balance_of(token,who.clone()))
+		}
+
+		pub fn _propose(who: T::AccountId, token: T::TokenId, kind: ProposalKind, terms: T::Hash) -> Result<u64, DispatchError> {
+			let balance = Self::balance_of(token,who.clone());
+			ensure!(!balance.is_zero(),Error::<T>::NotATokenHolder);
+			let supply = Self::get_total_supply(token).unwrap_or_default();
+			ensure!(balance >= T::MinProposalStake::get().mul_ceil(supply),Error::<T>::ProposerStakeTooLow);
+			let mut open = Self::open_proposals(token);
+			ensure!((open.len() as u32) < T::MaxOpenProposals::get(),Error::<T>::TooManyOpenProposals);
+
+			let id = Self::holder_proposal_count().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let proposal = HolderProposal::<T> {
+				token,
+				proposer: who.clone(),
+				kind,
+				terms,
+				end: now + T::ProposalPeriod::get().into(),
+				supply,
+				ayes: Zero::zero(),
+				nays: Zero::zero(),
+				status: ProposalStatus::Open,
+			};
+			HolderProposals::<T>::insert(id,proposal);
+			HolderProposalCount::<T>::put(id);
+			open.push(id);
+			OpenProposals::<T>::insert(token,open);
+
+			Self::deposit_event(Event::HolderProposalCreated { id, token, kind, proposer: who });
+			Ok(id)
+		}
+
+		pub fn _close_proposal(id: u64) -> Result<ProposalStatus, DispatchError> {
+			let mut proposal = Self::get_holder_proposal(id).ok_or(Error::<T>::ProposalNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(proposal.status == ProposalStatus::Open,Error::<T>::ProposalAlreadyClosed);
+			ensure!(now >= proposal.end,Error::<T>::ProposalStillOpen);
+
+			proposal.status = match proposal.ayes > proposal.supply / 2u32.into() {
+				true => ProposalStatus::Passed,
+				false => ProposalStatus::Failed,
+			};
+			OpenProposals::<T>::mutate(proposal.token,|open| open.retain(|open_id| *open_id != id));
+			PendingCleanups::<T>::append(id);
+			let status = proposal.status;
+			HolderProposals::<T>::insert(id,proposal);
+
+			Self::deposit_event(Event::HolderProposalClosed { id, status });
+			Ok(status)
+		}
+
+		/// Remove up to `limit` snapshots and votes of closed proposals, oldest proposal first.
+		/// Returns how many were removed.
+		pub fn clean_up_proposals(limit: u32) -> u32 {
+			let mut pending = Self::get_pending_cleanups();
+			let mut removed = 0;
+			while let Some(&id) = pending.first() {
+				let snapshots = Snapshots::<T>::iter_key_prefix(id)
+					.take(limit.saturating_sub(removed) as usize)
+					.collect::<Vec<_>>();
+				for who in snapshots {
+					Snapshots::<T>::remove(id,&who);
+					removed += 1;
+				}
+				let votes = HolderVotes::<T>::iter_key_prefix(id)
+					.take(limit.saturating_sub(removed) as usize)
+					.collect::<Vec<_>>();
+				for who in votes {
+					HolderVotes::<T>::remove(id,&who);
+					removed += 1;
+				}
+				if removed >= limit {
+					break
+				}
+				pending.remove(0);
+			}
+			PendingCleanups::<T>::put(pending);
+			removed
+		}
+
+		pub fn _set_token_uri(id: T::TokenId, uri: Vec<u8>) -> DispatchResult {
+			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+			TokenURI::<T>::insert(id,uri.clone());
+			Self::deposit_event(Event::TokenURIUpdated { id, uri });
+			Ok(())
+		}
+
+		pub fn _set_token_license(id: T::TokenId, license: u32) -> DispatchResult {
+			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+			TokenLicense::<T>::insert(id,license);
+			Self::deposit_event(Event::TokenLicenseSet { id, license });
 			Ok(())
 		}
 
+		pub fn _set_royalty_split(id: T::TokenId, royalty_split: Vec<(T::AccountId,u16)>) -> DispatchResult {
+			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+			Self::check_royalty_split(royalty_split.clone())?;
+			RoyaltySplit::<T>::insert(id,royalty_split.clone());
+			Self::deposit_event(Event::RoyaltySplitSet { id, royalty_split });
+			Ok(())
+		}
+
+		pub fn _freeze_token(id: T::TokenId) -> DispatchResult {
+			ensure!(Self::token_exists(id),Error::<T>::TokenDoesNotExist);
+			FrozenTokens::<T>::insert(id,true);
+			Self::deposit_event(Event::TokenFrozen { id });
+			Ok(())
+		}
+
+		pub fn _thaw_token(id: T::TokenId) -> DispatchResult {
+			ensure!(Self::is_frozen(id),Error::<T>::NotFrozen);
+			FrozenTokens::<T>::remove(id);
+			Self::deposit_event(Event::TokenThawed { id });
+			Ok(())
+		}
+
+		pub fn _create_collection(id: u64, owner: T::AccountId) -> DispatchResult {
+			ensure!(!Collections::<T>::contains_key(id),Error::<T>::CollectionAlreadyExists);
+			Collections::<T>::insert(id,owner.clone());
+			Self::deposit_event(Event::CollectionCreated { id, owner });
+			Ok(())
+		}
+
+		pub fn _add_to_collection(id: u64, token: T::TokenId) -> DispatchResult {
+			ensure!(Collections::<T>::contains_key(id),Error::<T>::CollectionDoesNotExist);
+			ensure!(Self::token_exists(token),Error::<T>::TokenDoesNotExist);
+			ensure!(!TokenCollection::<T>::contains_key(token),Error::<T>::TokenAlreadyInCollection);
+			TokenCollection::<T>::insert(token,id);
+			CollectionTokens::<T>::insert(id,token,true);
+			Self::deposit_event(Event::TokenAddedToCollection { id, token });
+			Ok(())
+		}
+
+		pub fn tokens_of_collection(id: u64) -> Vec<T::TokenId> {
+			CollectionTokens::<T>::iter_key_prefix(id).collect()
+		}
+
 		pub fn token_exists(id: T::TokenId) -> bool {
 			let supply = Self::get_total_supply(id);
 			let out = match supply {
@@ -291,7 +817,7 @@ pub mod pallet {
 			let mut sum: u16 = 0;
 
 			for (_,b) in &royalty_split {
-				sum += b;
+				sum = sum.saturating_add(*b);
 			}
 
 			ensure!(sum == 10000,Error::<T>::RoyaltiesShouldSumUpTo10000);
@@ -1,9 +1,10 @@
 use crate as pallet_nft;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::{parameter_types, traits::{ConstU16, ConstU32, ConstU64}};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -48,6 +49,10 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const MinProposalStake: Perbill = Perbill::from_percent(10);
+}
+
 /// Balance of an account.
 pub type Balance = u128;
 
@@ -55,6 +60,13 @@ impl pallet_nft::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type TokenId = u128;
 	type Balance = Balance;
+	type MintOrigin = frame_system::EnsureRoot<u64>;
+	type FreezeOrigin = frame_system::EnsureRoot<u64>;
+	type ProposalPeriod = ConstU32<10>;
+	type MaxOpenProposals = ConstU32<2>;
+	type MinProposalStake = MinProposalStake;
+	type MaxProposalCleanup = ConstU32<2>;
+	type TransferFilter = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,5 +1,6 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, Event, ProposalKind, ProposalStatus};
+use frame_support::{assert_noop, assert_ok, traits::OnIdle, weights::Weight};
+use sp_core::H256;
 
 #[test]
 fn it_works_for_mint_token() {
@@ -10,9 +11,12 @@ fn it_works_for_mint_token() {
 
 		assert_eq!(NftModule::get_tokens_count(),0);
 
-		assert_ok!(NftModule::mint(RuntimeOrigin::signed(1),1,10,1000,b"Token10".to_vec()));
+		// Only the mint origin can mint, so nobody can take the ids other pallets mint.
+		assert_noop!(NftModule::mint(RuntimeOrigin::signed(1),1,10,1000,b"Token10".to_vec()),sp_runtime::DispatchError::BadOrigin);
+		assert_ok!(NftModule::mint(RuntimeOrigin::root(),1,10,1000,b"Token10".to_vec()));
 		assert_eq!(NftModule::get_tokens_count(),1);
 		assert_eq!(NftModule::balance_of(10,1),1000);
+		assert_noop!(NftModule::mint(RuntimeOrigin::root(),2,10,1000,b"Token10".to_vec()),Error::<Test>::TokenAlreadyExists);
 	});
 }
 
@@ -25,10 +29,24 @@ fn it_works_for_batch_mint() {
 
 		assert_eq!(NftModule::get_tokens_count(),0);
 
-		assert_ok!(NftModule::mint_batch(RuntimeOrigin::signed(1),vec![1,2],10,vec![900,100],b"Token10".to_vec()));
+		assert_ok!(NftModule::mint_batch(RuntimeOrigin::root(),vec![1,2],10,vec![900,100],b"Token10".to_vec()));
 		assert_eq!(NftModule::get_tokens_count(),1);
 		assert_eq!(NftModule::balance_of(10,1),900);
 		assert_eq!(NftModule::balance_of(10,2),100);
+		assert_eq!(NftModule::get_total_supply(10),Some(1000));
+	});
+}
+
+#[test]
+fn it_sets_royalty_split_of_minted_token() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(NftModule::_set_royalty_split(10,vec![(1,10000)]),Error::<Test>::TokenDoesNotExist);
+		assert_ok!(NftModule::mint_batch(RuntimeOrigin::root(),vec![1,2],10,vec![60,30],b"Token10".to_vec()));
+		assert_noop!(NftModule::_set_royalty_split(10,vec![(1,6000),(2,3000)]),Error::<Test>::RoyaltiesShouldSumUpTo10000);
+		assert_ok!(NftModule::_set_royalty_split(10,vec![(1,6667),(2,3333)]));
+		assert_eq!(NftModule::royalty_split(10),Some(vec![(1,6667),(2,3333)]));
 	});
 }
 
@@ -41,7 +59,7 @@ fn it_works_for_mint_and_approve() {
 
 		assert_eq!(NftModule::get_tokens_count(),0);
 
-		assert_ok!(NftModule::mint(RuntimeOrigin::signed(1),1,10,1000,b"Token10".to_vec()));
+		assert_ok!(NftModule::mint(RuntimeOrigin::root(),1,10,1000,b"Token10".to_vec()));
 		assert_eq!(NftModule::get_tokens_count(),1);
 
 		// Check Apporval for account 2 (False)
@@ -67,7 +85,7 @@ fn it_works_for_mint_and_transfer() {
 
 		assert_eq!(NftModule::get_tokens_count(),0);
 
-		assert_ok!(NftModule::mint(RuntimeOrigin::signed(1),1,10,1000,b"Token10".to_vec()));
+		assert_ok!(NftModule::mint(RuntimeOrigin::root(),1,10,1000,b"Token10".to_vec()));
 		assert_eq!(NftModule::get_tokens_count(),1);
 
 		// Check Balances
@@ -82,5 +100,111 @@ fn it_works_for_mint_and_transfer() {
 
 		assert_eq!(NftModule::balance_of(10,1),800);
 		assert_eq!(NftModule::balance_of(10,2),200);
+
+		// An operator can transfer to themselves, but nobody can transfer to the sender.
+		assert_ok!(NftModule::set_approval_for_all(RuntimeOrigin::signed(1),2,true));
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(2),1,2,10,100));
+		assert_eq!(NftModule::balance_of(10,2),300);
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(2),1,1,10,100),Error::<Test>::SameAddress);
+	});
+}
+#[test]
+fn it_freezes_tokens_holdings_and_collections() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(NftModule::mint(RuntimeOrigin::root(),1,10,1000,b"Token10".to_vec()));
+		assert_ok!(NftModule::mint(RuntimeOrigin::root(),1,11,1000,b"Token11".to_vec()));
+		assert_noop!(NftModule::freeze_token(RuntimeOrigin::signed(1),10),sp_runtime::DispatchError::BadOrigin);
+
+		// One token
+		assert_ok!(NftModule::freeze_token(RuntimeOrigin::root(),10));
+		System::assert_last_event(Event::TokenFrozen { id: 10 }.into());
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(1),1,2,10,200),Error::<Test>::TokenIsFrozen);
+		assert_noop!(NftModule::batch_transfer(RuntimeOrigin::signed(1),1,2,vec![10,11],vec![200,200]),Error::<Test>::TokenIsFrozen);
+		assert_ok!(NftModule::thaw_token(RuntimeOrigin::root(),10));
+		assert_noop!(NftModule::thaw_token(RuntimeOrigin::root(),10),Error::<Test>::NotFrozen);
+
+		// One account's holding
+		assert_noop!(NftModule::freeze_holding(RuntimeOrigin::root(),10,3),Error::<Test>::NotATokenHolder);
+		assert_ok!(NftModule::freeze_holding(RuntimeOrigin::root(),10,1));
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(1),1,2,10,200),Error::<Test>::HoldingIsFrozen);
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(1),1,2,11,200));
+		assert_ok!(NftModule::thaw_holding(RuntimeOrigin::root(),10,1));
+
+		// The whole collection
+		assert_ok!(NftModule::_create_collection(1,1));
+		assert_ok!(NftModule::_add_to_collection(1,10));
+		assert_ok!(NftModule::freeze_collection(RuntimeOrigin::root(),1));
+		assert_noop!(NftModule::transfer(RuntimeOrigin::signed(1),1,2,10,200),Error::<Test>::CollectionIsFrozen);
+		assert_ok!(NftModule::thaw_collection(RuntimeOrigin::root(),1));
+		System::assert_last_event(Event::CollectionThawed { id: 1 }.into());
+
+		assert_ok!(NftModule::batch_transfer(RuntimeOrigin::signed(1),1,2,vec![10,11],vec![200,200]));
+		assert_eq!(NftModule::balance_of(10,2),200);
+		assert_eq!(NftModule::balance_of(11,2),400);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn it_weighs_holder_votes_by_balance_at_proposal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(NftModule::mint_batch(RuntimeOrigin::root(),vec![1,2,3],10,vec![500,300,200],b"Token10".to_vec()));
+		assert_noop!(NftModule::propose(RuntimeOrigin::signed(4),10,ProposalKind::ExhibitionLoan,H256::repeat_byte(1)),Error::<Test>::NotATokenHolder);
+		assert_ok!(NftModule::propose(RuntimeOrigin::signed(2),10,ProposalKind::ExhibitionLoan,H256::repeat_byte(1)));
+		System::assert_last_event(Event::HolderProposalCreated { id: 1, token: 10, kind: ProposalKind::ExhibitionLoan, proposer: 2 }.into());
+
+		// Moving tokens during the vote does not move voting weight.
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(2),2,3,10,100));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(3),1,true));
+		System::assert_last_event(Event::HolderVoted { id: 1, who: 3, aye: true, weight: 200 }.into());
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(2),1,false));
+		System::assert_last_event(Event::HolderVoted { id: 1, who: 2, aye: false, weight: 300 }.into());
+		assert_noop!(NftModule::vote(RuntimeOrigin::signed(2),1,true),Error::<Test>::AlreadyVoted);
+		assert_noop!(NftModule::close_proposal(RuntimeOrigin::signed(1),1),Error::<Test>::ProposalStillOpen);
+
+		// Ayes need more than half the supply.
+		System::set_block_number(11);
+		assert_noop!(NftModule::vote(RuntimeOrigin::signed(1),1,true),Error::<Test>::ProposalVotingEnded);
+		assert_ok!(NftModule::close_proposal(RuntimeOrigin::signed(1),1));
+		assert_eq!(NftModule::get_holder_proposal(1).unwrap().status, ProposalStatus::Failed);
+		assert!(NftModule::open_proposals(10).is_empty());
+
+		assert_ok!(NftModule::propose(RuntimeOrigin::signed(3),10,ProposalKind::ArtifactSale,H256::repeat_byte(2)));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(3),2,true));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(2),2,false));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(1),2,true));
+		System::set_block_number(21);
+		assert_ok!(NftModule::close_proposal(RuntimeOrigin::signed(1),2));
+		System::assert_last_event(Event::HolderProposalClosed { id: 2, status: ProposalStatus::Passed }.into());
+	});
+}
+
+#[test]
+fn it_requires_a_stake_and_cleans_up_closed_proposals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Proposers need a tenth of the supply.
+		assert_ok!(NftModule::mint_batch(RuntimeOrigin::root(),vec![1,2,3],10,vec![850,100,50],b"Token10".to_vec()));
+		assert_noop!(NftModule::propose(RuntimeOrigin::signed(3),10,ProposalKind::Licensing,H256::repeat_byte(1)),Error::<Test>::ProposerStakeTooLow);
+		assert_ok!(NftModule::propose(RuntimeOrigin::signed(2),10,ProposalKind::Licensing,H256::repeat_byte(1)));
+		assert_ok!(NftModule::transfer(RuntimeOrigin::signed(1),1,3,10,100));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(1),1,true));
+		assert_ok!(NftModule::vote(RuntimeOrigin::signed(3),1,true));
+
+		// Two snapshots and two votes are removed at most two a block once the proposal closes.
+		System::set_block_number(11);
+		assert_ok!(NftModule::close_proposal(RuntimeOrigin::signed(1),1));
+		assert_eq!(NftModule::get_pending_cleanups(), vec![1]);
+		NftModule::on_idle(11, Weight::MAX);
+		assert_eq!(NftModule::get_snapshot(1,1), None);
+		assert!(NftModule::has_voted(1,1));
+		NftModule::on_idle(12, Weight::MAX);
+		assert!(!NftModule::has_voted(1,1) && !NftModule::has_voted(1,3));
+		NftModule::on_idle(13, Weight::MAX);
+		assert!(NftModule::get_pending_cleanups().is_empty());
+	});
+}
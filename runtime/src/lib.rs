@@ -15,7 +15,7 @@ use sp_runtime::{
 	traits::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,SaturatedConversion,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -105,10 +105,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	// Bumped whenever storage or calls change, so `on_runtime_upgrade` runs the pending
+	//   `Migrations`.
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped whenever the encoding of calls changes.
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -148,7 +151,8 @@ parameter_types! {
 		);
 	pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
 		::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
-	pub const SS58Prefix: u8 = 42;
+	/// Address format of BHDAO accounts, to be registered in the ss58-registry before launch.
+	pub const SS58Prefix: u16 = 1871;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -260,24 +264,94 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 }
 
 pub const VOTING_WINDOW: u32 = 1000;
+pub const REVEAL_WINDOW: u32 = 250;
 pub const MAX_PRICE: u32 = 100000000;
 
+parameter_types! {
+	pub const BhdaoUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	pub const ReputationDecay: Perbill = Perbill::from_percent(10);
+	pub const VetoThreshold: Perbill = Perbill::from_percent(33);
+	pub const MemberActionQuorum: Perbill = Perbill::from_percent(50);
+	pub const MinProposalStake: Perbill = Perbill::from_percent(1);
+}
+
 /// Configure the pallet-bhdao in pallets/bhdao.
 impl pallet_bhdao::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
 	type VotingWindow = ConstU32<VOTING_WINDOW>;
+	type AttesterId = pallet_bhdao::crypto::AttesterId;
+	type UnsignedPriority = BhdaoUnsignedPriority;
+	type AuditPeriod = ConstU32<DAYS>;
+	type AuditSampleSize = ConstU32<10>;
+	type MaxAuditFailures = ConstU32<3>;
+	type MaxOpenBallots = ConstU32<128>;
+	type MaxDelegators = ConstU32<256>;
+	type RevealWindow = ConstU32<REVEAL_WINDOW>;
+	type ObjectionDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type MaxObjectionEvidence = ConstU32<16>;
+	type Randomness = RandomnessCollectiveFlip;
+	type AppealDeposit = ConstU128<{ 200 * EXISTENTIAL_DEPOSIT }>;
+	type AppealPeriod = ConstU32<{ 7 * DAYS }>;
+	type AppealPanelSize = ConstU32<5>;
+	type ReviewPanelSize = ConstU32<5>;
+	type MaxExperts = ConstU32<1024>;
+	type MaxCategories = ConstU32<8>;
+	type MaxCollectionSize = ConstU32<256>;
+	type MaxCoContributors = ConstU32<8>;
+	type MaxSubjects = ConstU32<16>;
+	type MinKycAttestations = ConstU32<2>;
+	type CollectorEarlyAccess = ConstU32<{ 3 * DAYS }>;
+	type AccurateVoteReward = ConstU32<1>;
+	type MaxSettlements = ConstU32<64>;
+	type VerifiedUploadReward = ConstU32<10>;
+	type RejectedUploadPenalty = ConstU32<5>;
+	type UpheldObjectionPenalty = ConstU32<5>;
+	type ContributorThreshold = ConstU32<10>;
+	type ReputationDecayPeriod = ConstU32<{ 30 * DAYS }>;
+	type ReputationDecay = ReputationDecay;
+	type VetoThreshold = VetoThreshold;
+	type ApplicationDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type MemberActionDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type MemberActionQuorum = MemberActionQuorum;
+	type AmendmentDeposit = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+	type MaxAmendments = ConstU32<16>;
+	type TakedownCooldown = ConstU32<{ 30 * DAYS }>;
+	type TakedownDeposit = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
 }
 
 impl pallet_nft::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TokenId = u128;
 	type Balance = Balance;
+	type MintOrigin = frame_system::EnsureRoot<AccountId>;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type ProposalPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxOpenProposals = ConstU32<16>;
+	type MinProposalStake = MinProposalStake;
+	type MaxProposalCleanup = ConstU32<512>;
+	type TransferFilter = BhdaoModule;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -298,6 +372,7 @@ construct_runtime!(
 		// Include the custom logic from the pallet-template in the runtime.
 		BhdaoModule: pallet_bhdao,
 		NftModule: pallet_nft,
+		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
 	}
 );
 
@@ -324,6 +399,20 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade. Each one is a no-op unless the pallet's
+/// storage is at the version right before it.
+pub type Migrations = (
+	pallet_bhdao::migrations::v1::MigrateToV1<Runtime>,
+	pallet_bhdao::migrations::v2::MigrateToV2<Runtime>,
+	pallet_bhdao::migrations::v3::MigrateToV3<Runtime>,
+	pallet_bhdao::migrations::v4::MigrateToV4<Runtime>,
+	pallet_bhdao::migrations::v5::MigrateToV5<Runtime>,
+	pallet_bhdao::migrations::v6::MigrateToV6<Runtime>,
+	pallet_bhdao::migrations::v7::MigrateToV7<Runtime>,
+	pallet_bhdao::migrations::v8::MigrateToV8<Runtime>,
+	pallet_bhdao::migrations::v9::MigrateToV9<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -331,6 +420,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -508,6 +598,36 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_bhdao_runtime_api::BhdaoApi<Block, AccountId, pallet_bhdao::Roles, pallet_bhdao::UnmetRequirement, pallet_bhdao::VoteType, pallet_bhdao::Tally, u128, pallet_bhdao::License<Hash>> for Runtime {
+		fn experts_in_domain(domain: u32) -> Vec<AccountId> {
+			BhdaoModule::experts_in_domain(domain)
+		}
+
+		fn domains_of(who: AccountId) -> Vec<u32> {
+			BhdaoModule::domains_of(&who)
+		}
+
+		fn role_progress(who: AccountId) -> Vec<(pallet_bhdao::Roles, Vec<pallet_bhdao::UnmetRequirement>)> {
+			BhdaoModule::role_progress(&who)
+		}
+
+		fn ballot_tally(vote_type: pallet_bhdao::VoteType, uid: u64) -> Option<pallet_bhdao::Tally> {
+			BhdaoModule::ballot_tally(vote_type, uid)
+		}
+
+		fn collection_tokens(collection: u64) -> Vec<u128> {
+			BhdaoModule::collection_tokens(collection)
+		}
+
+		fn upload_license(upload_id: u64) -> Option<pallet_bhdao::License<Hash>> {
+			BhdaoModule::upload_license(upload_id)
+		}
+
+		fn token_license(token: u128) -> Option<pallet_bhdao::License<Hash>> {
+			BhdaoModule::token_license(token)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (